@@ -1,11 +1,17 @@
 use crate::as_impl::impl_as;
-use crate::traits::{IntoOwned, ToBorrowed, ToOwned};
+use crate::leak::Leaker;
+use crate::map::CowMapper;
+use crate::traits::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, OwnableVisit,
+    Rebase, ToBorrowed, ToOwned,
+};
+use crate::visit::{FieldPath, Visitor};
 use alloc::string::String;
 use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
 use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, Range};
 
 /// Transparent wrapper for [`Clone`]able types to support all traits (by cloning).
 ///
@@ -28,27 +34,88 @@ use core::ops::{Deref, DerefMut};
 pub struct AsClone<T: Clone>(pub T);
 
 impl<T: Clone> ToBorrowed<'_> for AsClone<T> {
-    #[inline(always)]
-    fn to_borrowed(&self) -> Self {
-        AsClone(self.0.clone())
+    inline_always! {
+        fn to_borrowed(&self) -> Self {
+            AsClone(self.0.clone())
+        }
     }
 }
 
 impl<T: Clone> ToOwned for AsClone<T> {
     type Owned = AsClone<T>;
 
-    #[inline(always)]
-    fn to_owned(&self) -> Self::Owned {
-        AsClone(self.0.clone())
+    inline_always! {
+        fn to_owned(&self) -> Self::Owned {
+            AsClone(self.0.clone())
+        }
     }
 }
 
 impl<T: Clone> IntoOwned for AsClone<T> {
     type Owned = AsClone<T>;
 
-    #[inline(always)]
-    fn into_owned(self) -> Self::Owned {
-        AsClone(self.0)
+    inline_always! {
+        fn into_owned(self) -> Self::Owned {
+            AsClone(self.0)
+        }
+    }
+}
+
+impl<T: Clone> IntoOwnedCompact for AsClone<T> {
+    inline_always! {
+        fn into_owned_compact(self) -> Self::Owned {
+            AsClone(self.0)
+        }
+    }
+}
+
+impl<T: Clone> IntoLeaked for AsClone<T> {
+    type Leaked = AsClone<T>;
+
+    inline_always! {
+        fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {
+            AsClone(self.0)
+        }
+    }
+}
+
+impl<T: Clone> Rebase<'_> for AsClone<T> {
+    type Rebased = AsClone<T>;
+
+    inline_always! {
+        fn rebase(&self, _old: &[u8], _new: &[u8]) -> Self::Rebased {
+            AsClone(self.0.clone())
+        }
+    }
+}
+
+impl<T: Clone> ForEachBorrow for AsClone<T> {
+    inline_always! {
+        fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+    }
+}
+
+impl<T: Clone> OwnableVisit for AsClone<T> {
+    inline_always! {
+        fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+    }
+}
+
+impl<T: Clone> MapCows for AsClone<T> {
+    inline_always! {
+        fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+            self
+        }
+    }
+}
+
+impl<T: Clone> AsBorrowed<'_> for AsClone<T> {
+    type Borrowed = AsClone<T>;
+
+    inline_always! {
+        fn as_borrowed(&self) -> Self::Borrowed {
+            AsClone(self.0.clone())
+        }
     }
 }
 
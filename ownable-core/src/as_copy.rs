@@ -1,10 +1,16 @@
 use crate::as_impl::impl_as;
-use crate::traits::{IntoOwned, ToBorrowed, ToOwned};
+use crate::leak::Leaker;
+use crate::map::CowMapper;
+use crate::traits::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, OwnableVisit,
+    Rebase, ToBorrowed, ToOwned,
+};
+use crate::visit::{FieldPath, Visitor};
 use core::borrow::{Borrow, BorrowMut};
 use core::cmp::Ordering;
 use core::fmt::{Debug, Display, Formatter};
 use core::hash::{Hash, Hasher};
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, Range};
 
 /// Transparent wrapper for [`Copy`]able types to support all traits (by copying).
 ///
@@ -27,27 +33,88 @@ use core::ops::{Deref, DerefMut};
 pub struct AsCopy<T: Copy>(pub T);
 
 impl<T: Copy> ToBorrowed<'_> for AsCopy<T> {
-    #[inline(always)]
-    fn to_borrowed(&self) -> Self {
-        AsCopy(self.0)
+    inline_always! {
+        fn to_borrowed(&self) -> Self {
+            AsCopy(self.0)
+        }
     }
 }
 
 impl<T: Copy> ToOwned for AsCopy<T> {
     type Owned = AsCopy<T>;
 
-    #[inline(always)]
-    fn to_owned(&self) -> Self::Owned {
-        AsCopy(self.0)
+    inline_always! {
+        fn to_owned(&self) -> Self::Owned {
+            AsCopy(self.0)
+        }
     }
 }
 
 impl<T: Copy> IntoOwned for AsCopy<T> {
     type Owned = AsCopy<T>;
 
-    #[inline(always)]
-    fn into_owned(self) -> Self::Owned {
-        AsCopy(self.0)
+    inline_always! {
+        fn into_owned(self) -> Self::Owned {
+            AsCopy(self.0)
+        }
+    }
+}
+
+impl<T: Copy> IntoOwnedCompact for AsCopy<T> {
+    inline_always! {
+        fn into_owned_compact(self) -> Self::Owned {
+            AsCopy(self.0)
+        }
+    }
+}
+
+impl<T: Copy> IntoLeaked for AsCopy<T> {
+    type Leaked = AsCopy<T>;
+
+    inline_always! {
+        fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {
+            AsCopy(self.0)
+        }
+    }
+}
+
+impl<T: Copy> Rebase<'_> for AsCopy<T> {
+    type Rebased = AsCopy<T>;
+
+    inline_always! {
+        fn rebase(&self, _old: &[u8], _new: &[u8]) -> Self::Rebased {
+            AsCopy(self.0)
+        }
+    }
+}
+
+impl<T: Copy> ForEachBorrow for AsCopy<T> {
+    inline_always! {
+        fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+    }
+}
+
+impl<T: Copy> OwnableVisit for AsCopy<T> {
+    inline_always! {
+        fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+    }
+}
+
+impl<T: Copy> MapCows for AsCopy<T> {
+    inline_always! {
+        fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+            self
+        }
+    }
+}
+
+impl<T: Copy> AsBorrowed<'_> for AsCopy<T> {
+    type Borrowed = AsCopy<T>;
+
+    inline_always! {
+        fn as_borrowed(&self) -> Self::Borrowed {
+            AsCopy(self.0)
+        }
     }
 }
 
@@ -0,0 +1,88 @@
+//! Inspect which `Cow` values are borrowed or owned (e.g. to find unexpected allocations).
+//!
+//! This is based on [`OwnableVisit`].
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable_core::debug::{Ownership, Summary};
+//! let value: Vec<Cow<'_, str>> = vec![Cow::Borrowed("borrowed"), Cow::Owned("owned".into())];
+//! assert_eq!(format!("{:?}", Ownership(&value)), "{[0]: B(8), [1]: O(5)}");
+//!
+//! let summary = Summary::of(&value);
+//! assert_eq!((summary.borrowed, summary.borrowed_bytes), (1, 8));
+//! assert_eq!((summary.owned, summary.owned_bytes), (1, 5));
+//! ```
+
+use crate::visit::{FieldPath, Visitor};
+use crate::OwnableVisit;
+use alloc::borrow::{Borrow, ToOwned};
+use core::fmt::{Debug, DebugMap, Formatter};
+use core::mem::size_of_val;
+
+/// Wrapper whose `Debug` output lists each `Cow` value by its path, annotated with `B` (borrowed)
+/// or `O` (owned) and the size in bytes.
+#[derive(Clone, Copy)]
+pub struct Ownership<'a, T: ?Sized>(pub &'a T);
+
+impl<T: OwnableVisit + ?Sized> Debug for Ownership<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let mut map = DebugVisitor(f.debug_map());
+        self.0.visit(&FieldPath::root(), &mut map);
+        map.0.finish()
+    }
+}
+
+struct DebugVisitor<'a, 'b>(DebugMap<'a, 'b>);
+
+impl Visitor for DebugVisitor<'_, '_> {
+    fn visit_borrowed<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, value: &T) {
+        self.0.entry(
+            &format_args!("{path}"),
+            &format_args!("B({})", size_of_val(value)),
+        );
+    }
+
+    fn visit_owned<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, value: &T::Owned) {
+        let value: &T = value.borrow();
+        self.0.entry(
+            &format_args!("{path}"),
+            &format_args!("O({})", size_of_val(value)),
+        );
+    }
+}
+
+/// The number and size in bytes of the borrowed and owned `Cow` values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// The number of borrowed values.
+    pub borrowed: usize,
+    /// The size of the borrowed values in bytes.
+    pub borrowed_bytes: usize,
+    /// The number of owned values.
+    pub owned: usize,
+    /// The size of the owned values in bytes (without excess capacity).
+    pub owned_bytes: usize,
+}
+
+impl Summary {
+    /// Count the borrowed and owned values of `value`.
+    #[must_use]
+    pub fn of<T: OwnableVisit + ?Sized>(value: &T) -> Self {
+        let mut summary = Summary::default();
+        value.visit(&FieldPath::root(), &mut summary);
+        summary
+    }
+}
+
+impl Visitor for Summary {
+    fn visit_borrowed<T: ToOwned + ?Sized>(&mut self, _path: &FieldPath<'_>, value: &T) {
+        self.borrowed += 1;
+        self.borrowed_bytes += size_of_val(value);
+    }
+
+    fn visit_owned<T: ToOwned + ?Sized>(&mut self, _path: &FieldPath<'_>, value: &T::Owned) {
+        let value: &T = value.borrow();
+        self.owned += 1;
+        self.owned_bytes += size_of_val(value);
+    }
+}
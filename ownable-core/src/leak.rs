@@ -0,0 +1,83 @@
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use alloc::vec::Vec;
+
+/// Leaks the payloads for [`IntoLeaked`](crate::IntoLeaked), optionally identical strings are
+/// leaked only once.
+///
+/// Only `str` payloads are interned, all others (e.g. `[u8]`) are always leaked separately, since
+/// they are not required to be comparable.
+///
+/// ```rust
+/// # use std::borrow::Cow;
+/// # use ownable_core::{IntoLeaked, Leaker};
+/// let mut leaker = Leaker::interning();
+/// let a: Cow<'static, str> = Cow::Borrowed(&*String::from("value")).into_leaked(&mut leaker);
+/// let b: Cow<'static, str> = Cow::<str>::Owned(String::from("value")).into_leaked(&mut leaker);
+/// // both point to the same leaked memory
+/// assert!(core::ptr::eq(a.as_ref(), b.as_ref()));
+/// ```
+#[derive(Debug, Default)]
+pub struct Leaker {
+    strings: Option<BTreeSet<&'static str>>,
+}
+
+impl Leaker {
+    /// Create a leaker which leaks every payload separately.
+    #[must_use]
+    pub const fn new() -> Self {
+        Leaker { strings: None }
+    }
+
+    /// Create a leaker which leaks identical strings only once (other payloads are not interned).
+    #[must_use]
+    pub fn interning() -> Self {
+        Leaker {
+            strings: Some(BTreeSet::new()),
+        }
+    }
+
+    /// Leak the value, or return the already leaked identical one.
+    pub fn leak_str(&mut self, value: Cow<'_, str>) -> &'static str {
+        match &mut self.strings {
+            None => Box::leak(value.into_owned().into_boxed_str()),
+            Some(strings) => {
+                if let Some(leaked) = strings.get(value.as_ref()) {
+                    leaked
+                } else {
+                    let leaked: &'static str = Box::leak(value.into_owned().into_boxed_str());
+                    strings.insert(leaked);
+                    leaked
+                }
+            }
+        }
+    }
+}
+
+/// A payload of a [`Cow`] which can be leaked, see [`IntoLeaked`](crate::IntoLeaked).
+pub trait Leakable: ToOwned {
+    /// Leak the value (it's only copied if it's borrowed).
+    fn leak(value: Cow<'_, Self>, leaker: &mut Leaker) -> &'static Self;
+}
+
+impl Leakable for str {
+    #[inline]
+    fn leak(value: Cow<'_, Self>, leaker: &mut Leaker) -> &'static Self {
+        leaker.leak_str(value)
+    }
+}
+
+impl<T: Clone + 'static> Leakable for [T] {
+    #[inline]
+    fn leak(value: Cow<'_, Self>, _leaker: &mut Leaker) -> &'static Self {
+        Box::leak(Vec::into_boxed_slice(value.into_owned()))
+    }
+}
+
+impl<T: Clone + 'static> Leakable for T {
+    #[inline]
+    fn leak(value: Cow<'_, Self>, _leaker: &mut Leaker) -> &'static Self {
+        Box::leak(Box::new(value.into_owned()))
+    }
+}
@@ -11,14 +11,54 @@
 //! macro related dependencies that get used for the derive macros. If you don't rely on the derive
 //! macros then you may benefit from pulling in fewer dependencies by relying directly on
 //! `ownable-core`.
+//!
+//! With the feature `derive` the derive macros are available as well, the generated code then has
+//! to refer to this crate with `#[ownable(crate = "ownable_core")]`.
 
 extern crate alloc;
 
 pub use crate::as_clone::AsClone;
 pub use crate::as_copy::AsCopy;
-pub use crate::traits::{IntoOwned, ToBorrowed, ToOwned};
+pub use crate::leak::{Leakable, Leaker};
+pub use crate::traits::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, OwnableVisit,
+    Rebase, ToBorrowed, ToOwned,
+};
+#[cfg(feature = "rayon")]
+pub use crate::traits::{ParIntoOwned, ParToOwned};
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use ownable_macro::{
+    ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, Ownable, OwnableVisit, Rebase,
+    ToBorrowed, ToOwned,
+};
+
+// Marks the functions as `#[inline(always)]`, or only as `#[inline]` with the feature
+// `no_inline_always`.
+macro_rules! inline_always {
+    ($($item:item)*) => {
+        $(
+            #[cfg_attr(not(feature = "no_inline_always"), inline(always))]
+            #[cfg_attr(feature = "no_inline_always", inline)]
+            $item
+        )*
+    };
+}
 
 mod as_clone;
 mod as_copy;
 mod as_impl;
-mod traits;
+pub mod debug;
+mod leak;
+pub mod map;
+#[cfg(feature = "stack_safe")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stack_safe")))]
+pub mod stack;
+pub mod traits;
+pub mod visit;
+
+// Used by the derive macros.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::borrow::{Cow, ToOwned};
+}
@@ -0,0 +1,76 @@
+//! Transform all `Cow` values within a structure, see [`MapCows`](crate::MapCows).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable_core::MapCows;
+//! # use ownable_core::map::str_mapper;
+//! let value: Vec<Cow<'_, str>> = vec![Cow::Borrowed(" borrowed "), Cow::Owned("owned".into())];
+//! let value = value.map_cows(&mut str_mapper(|s| Cow::Borrowed(s.trim())));
+//! assert_eq!(value, ["borrowed", "owned"]);
+//! assert!(matches!(value[0], Cow::Borrowed(_)));
+//! assert!(matches!(value[1], Cow::Owned(_)));
+//! ```
+
+use alloc::borrow::Cow;
+
+/// The transformation of each `Cow` value.
+///
+/// Returning the input (or a part of it) borrowed keeps it borrowed (or owned, if it was owned and
+/// the input is returned unchanged), otherwise the returned value is owned.
+pub trait CowMapper {
+    /// Transform a string, by default it's unchanged.
+    fn map_str<'c>(&mut self, value: &'c str) -> Cow<'c, str> {
+        Cow::Borrowed(value)
+    }
+
+    /// Transform a byte slice, by default it's unchanged.
+    fn map_bytes<'c>(&mut self, value: &'c [u8]) -> Cow<'c, [u8]> {
+        Cow::Borrowed(value)
+    }
+}
+
+/// A [`CowMapper`] which transforms only strings, see [`str_mapper`].
+#[derive(Clone, Copy, Debug)]
+pub struct StrMapper<F>(F);
+
+/// Create a [`CowMapper`] from a closure, which transforms only strings.
+#[inline]
+pub fn str_mapper<F>(f: F) -> StrMapper<F>
+where
+    F: for<'c> FnMut(&'c str) -> Cow<'c, str>,
+{
+    StrMapper(f)
+}
+
+impl<F> CowMapper for StrMapper<F>
+where
+    F: for<'c> FnMut(&'c str) -> Cow<'c, str>,
+{
+    #[inline]
+    fn map_str<'c>(&mut self, value: &'c str) -> Cow<'c, str> {
+        (self.0)(value)
+    }
+}
+
+/// A [`CowMapper`] which transforms only byte slices, see [`bytes_mapper`].
+#[derive(Clone, Copy, Debug)]
+pub struct BytesMapper<F>(F);
+
+/// Create a [`CowMapper`] from a closure, which transforms only byte slices.
+#[inline]
+pub fn bytes_mapper<F>(f: F) -> BytesMapper<F>
+where
+    F: for<'c> FnMut(&'c [u8]) -> Cow<'c, [u8]>,
+{
+    BytesMapper(f)
+}
+
+impl<F> CowMapper for BytesMapper<F>
+where
+    F: for<'c> FnMut(&'c [u8]) -> Cow<'c, [u8]>,
+{
+    #[inline]
+    fn map_bytes<'c>(&mut self, value: &'c [u8]) -> Cow<'c, [u8]> {
+        (self.0)(value)
+    }
+}
@@ -0,0 +1,168 @@
+//! Support for the conversion of deeply nested and recursive types.
+//!
+//! Each conversion of a derived type with `#[ownable(stack_safe)]` runs within [`guard`], which
+//! grows the stack on the heap when it's about to be exhausted. Optionally the nesting depth can
+//! be limited with [`with_max_depth`].
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable_core::stack::{guard, with_max_depth, DepthExceeded};
+//! # use ownable_core::IntoOwned;
+//! struct List<'a> {
+//!     value: Cow<'a, str>,
+//!     next: Option<Box<List<'a>>>,
+//! }
+//!
+//! // A hand written impl, the derive does the same with `#[ownable(stack_safe)]`.
+//! impl IntoOwned for List<'_> {
+//!     type Owned = List<'static>;
+//!
+//!     fn into_owned(self) -> Self::Owned {
+//!         guard(move || List {
+//!             value: IntoOwned::into_owned(self.value),
+//!             next: IntoOwned::into_owned(self.next),
+//!         })
+//!     }
+//! }
+//!
+//! let list = List {
+//!     value: Cow::Borrowed("first"),
+//!     next: Some(Box::new(List {
+//!         value: Cow::Borrowed("second"),
+//!         next: None,
+//!     })),
+//! };
+//! assert_eq!(
+//!     with_max_depth(1, || list.into_owned()).err(),
+//!     Some(DepthExceeded { max_depth: 1 })
+//! );
+//! ```
+
+use core::cell::Cell;
+use core::fmt::{Display, Formatter};
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+// The minimum of stack which has to be available before a conversion is started.
+const RED_ZONE: usize = 64 * 1024;
+// The size of the stack which is allocated when the remaining stack is less than the red zone.
+const STACK_SIZE: usize = 1024 * 1024;
+
+std::thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+    static MAX_DEPTH: Cell<usize> = const { Cell::new(usize::MAX) };
+}
+
+/// The conversion was aborted because the structure is nested deeper than allowed.
+///
+/// See [`with_max_depth`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DepthExceeded {
+    /// The maximum depth which was exceeded.
+    pub max_depth: usize,
+}
+
+impl Display for DepthExceeded {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "the maximum depth of {} is exceeded", self.max_depth)
+    }
+}
+
+impl std::error::Error for DepthExceeded {}
+
+// Decrements the depth when a level is left, also while unwinding.
+struct Level;
+
+impl Drop for Level {
+    #[inline]
+    fn drop(&mut self) {
+        DEPTH.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Run one level of a conversion, with enough stack available.
+///
+/// This is used by the derived code with `#[ownable(stack_safe)]` and should wrap the body of
+/// hand written impls of recursive types.
+///
+/// # Panics
+///
+/// Unwinds with a payload of [`DepthExceeded`] (without calling the panic hook) if the maximum
+/// depth set by [`with_max_depth`] is exceeded. The `Drop` impls and any `catch_unwind` within the
+/// conversion see this unwinding, and with `panic = "abort"` the process is aborted instead.
+#[inline]
+pub fn guard<R, F: FnOnce() -> R>(f: F) -> R {
+    let depth = DEPTH.with(|depth| {
+        depth.set(depth.get() + 1);
+        depth.get()
+    });
+    let _level = Level;
+    let max_depth = MAX_DEPTH.with(Cell::get);
+    if depth > max_depth {
+        resume_unwind(Box::new(DepthExceeded { max_depth }));
+    }
+    stacker::maybe_grow(RED_ZONE, STACK_SIZE, f)
+}
+
+/// Run `f` (usually a conversion) and abort it if it's nested deeper than `max_depth`.
+///
+/// Only the levels of types with `#[ownable(stack_safe)]` (or which use [`guard`]) are counted,
+/// starting at this call (i.e. the levels of an enclosing conversion aren't counted).
+///
+/// The depth and the limit are kept per thread, they are passed on to the worker threads of the
+/// parallel conversions (`ParToOwned` and `ParIntoOwned` with the feature `rayon`, also with
+/// `#[ownable(parallel)]`), but not to threads which are started otherwise by `f`.
+///
+/// The conversion is aborted by unwinding (the panic hook isn't called), thus it requires
+/// `panic = "unwind"` (which is the default), with `panic = "abort"` the process is aborted
+/// instead. The unwinding is also seen by the `Drop` impls and any `catch_unwind` within `f`, the
+/// payload is [`DepthExceeded`].
+///
+/// # Errors
+///
+/// If `max_depth` was exceeded.
+pub fn with_max_depth<R, F: FnOnce() -> R>(max_depth: usize, f: F) -> Result<R, DepthExceeded> {
+    let context = Context {
+        depth: 0,
+        max_depth,
+    };
+    match context.run(|| catch_unwind(AssertUnwindSafe(f))) {
+        Ok(result) => Ok(result),
+        Err(payload) => match payload.downcast::<DepthExceeded>() {
+            Ok(error) => Err(*error),
+            Err(payload) => resume_unwind(payload),
+        },
+    }
+}
+
+// The depth and the limit of a thread, which are passed on to worker threads.
+#[derive(Clone, Copy)]
+pub(crate) struct Context {
+    depth: usize,
+    max_depth: usize,
+}
+
+impl Context {
+    pub(crate) fn current() -> Self {
+        Self {
+            depth: DEPTH.with(Cell::get),
+            max_depth: MAX_DEPTH.with(Cell::get),
+        }
+    }
+
+    // Run `f` with this context, the previous one is restored afterwards (also while unwinding).
+    pub(crate) fn run<R>(self, f: impl FnOnce() -> R) -> R {
+        struct Restore(Context);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                DEPTH.with(|depth| depth.set(self.0.depth));
+                MAX_DEPTH.with(|max_depth| max_depth.set(self.0.max_depth));
+            }
+        }
+
+        let _restore = Restore(Self::current());
+        DEPTH.with(|depth| depth.set(self.depth));
+        MAX_DEPTH.with(|max_depth| max_depth.set(self.max_depth));
+        f()
+    }
+}
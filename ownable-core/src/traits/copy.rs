@@ -1,26 +1,175 @@
-use crate::traits::{IntoOwned, ToBorrowed, ToOwned};
+use crate::leak::Leaker;
+use crate::map::CowMapper;
+use crate::traits::rebase_range;
+use crate::traits::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, OwnableVisit,
+    Rebase, ToBorrowed, ToOwned,
+};
+use crate::visit::{FieldPath, Visitor};
+use alloc::borrow::Cow;
+use core::marker::PhantomData;
 use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
 use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::Range;
 
 // ()
 
+impl AsBorrowed<'_> for () {
+    type Borrowed = ();
+
+    inline_always! {
+        fn as_borrowed(&self) -> Self::Borrowed {}
+    }
+}
+
 impl ToBorrowed<'_> for () {
-    #[inline(always)]
-    fn to_borrowed(&self) -> Self {}
+    inline_always! {
+        fn to_borrowed(&self) -> Self {}
+    }
 }
 
 impl ToOwned for () {
     type Owned = ();
 
-    #[inline(always)]
-    fn to_owned(&self) -> Self::Owned {}
+    inline_always! {
+        fn to_owned(&self) -> Self::Owned {}
+    }
 }
 
 impl IntoOwned for () {
     type Owned = ();
 
-    #[inline(always)]
-    fn into_owned(self) -> Self::Owned {}
+    inline_always! {
+        fn into_owned(self) -> Self::Owned {}
+    }
+}
+
+impl IntoOwnedCompact for () {
+    inline_always! {
+        fn into_owned_compact(self) -> Self::Owned {}
+    }
+}
+
+impl IntoLeaked for () {
+    type Leaked = ();
+
+    inline_always! {
+        fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {}
+    }
+}
+
+impl Rebase<'_> for () {
+    type Rebased = ();
+
+    inline_always! {
+        fn rebase(&self, _old: &[u8], _new: &[u8]) -> Self::Rebased {}
+    }
+}
+
+impl ForEachBorrow for () {
+    inline_always! {
+        fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+    }
+}
+
+impl OwnableVisit for () {
+    inline_always! {
+        fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+    }
+}
+
+impl MapCows for () {
+    inline_always! {
+        fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {}
+    }
+}
+
+// PhantomData
+
+impl<T: ?Sized> AsBorrowed<'_> for PhantomData<T> {
+    type Borrowed = PhantomData<T>;
+
+    inline_always! {
+        fn as_borrowed(&self) -> Self::Borrowed {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> ToBorrowed<'_> for PhantomData<T> {
+    inline_always! {
+        fn to_borrowed(&self) -> Self {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> ToOwned for PhantomData<T> {
+    type Owned = PhantomData<T>;
+
+    inline_always! {
+        fn to_owned(&self) -> Self::Owned {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> IntoOwned for PhantomData<T> {
+    type Owned = PhantomData<T>;
+
+    inline_always! {
+        fn into_owned(self) -> Self::Owned {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> IntoOwnedCompact for PhantomData<T> {
+    inline_always! {
+        fn into_owned_compact(self) -> Self::Owned {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> IntoLeaked for PhantomData<T> {
+    type Leaked = PhantomData<T>;
+
+    inline_always! {
+        fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> Rebase<'_> for PhantomData<T> {
+    type Rebased = PhantomData<T>;
+
+    inline_always! {
+        fn rebase(&self, _old: &[u8], _new: &[u8]) -> Self::Rebased {
+            PhantomData
+        }
+    }
+}
+
+impl<T: ?Sized> ForEachBorrow for PhantomData<T> {
+    inline_always! {
+        fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+    }
+}
+
+impl<T: ?Sized> OwnableVisit for PhantomData<T> {
+    inline_always! {
+        fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+    }
+}
+
+impl<T: ?Sized> MapCows for PhantomData<T> {
+    inline_always! {
+        fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+            self
+        }
+    }
 }
 
 // Copy
@@ -28,28 +177,89 @@ impl IntoOwned for () {
 macro_rules! copy_impl {
     () => {};
     ($t:ident) => {
+        copy_impl!($t {} {});
+    };
+    ($t:ident { $($map_cows:tt)* } { $($rebase:tt)* }) => {
+        impl AsBorrowed<'_> for $t {
+            type Borrowed = $t;
+
+            inline_always! {
+                fn as_borrowed(&self) -> Self::Borrowed {
+                    *self
+                }
+            }
+        }
         impl ToBorrowed<'_> for $t {
-            #[inline(always)]
-            fn to_borrowed(&self) -> Self {
-                *self
+            inline_always! {
+                fn to_borrowed(&self) -> Self {
+                    *self
+                }
             }
         }
         impl ToOwned for $t {
             type Owned = $t;
 
-            #[inline(always)]
-            fn to_owned(&self) -> Self::Owned {
-                *self
+            inline_always! {
+                fn to_owned(&self) -> Self::Owned {
+                    *self
+                }
             }
         }
         impl IntoOwned for $t {
             type Owned = $t;
 
-            #[inline(always)]
-            fn into_owned(self) -> Self::Owned {
-                self
+            inline_always! {
+                fn into_owned(self) -> Self::Owned {
+                    self
+                }
             }
         }
+        impl IntoOwnedCompact for $t {
+            inline_always! {
+                fn into_owned_compact(self) -> Self::Owned {
+                    self
+                }
+            }
+        }
+        impl IntoLeaked for $t {
+            type Leaked = $t;
+
+            inline_always! {
+                fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {
+                    self
+                }
+            }
+        }
+        impl<'b> Rebase<'b> for $t {
+            type Rebased = $t;
+
+            inline_always! {
+                fn rebase(&self, _old: &[u8], _new: &'b [u8]) -> Self::Rebased {
+                    *self
+                }
+            }
+
+            $($rebase)*
+        }
+        impl ForEachBorrow for $t {
+            inline_always! {
+                fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+            }
+        }
+        impl OwnableVisit for $t {
+            inline_always! {
+                fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+            }
+        }
+        impl MapCows for $t {
+            inline_always! {
+                fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+                    self
+                }
+            }
+
+            $($map_cows)*
+        }
     };
     ($t:ident, $($y:ident),+) => {
         copy_impl!($t);
@@ -57,7 +267,30 @@ macro_rules! copy_impl {
     };
 }
 
-copy_impl!(u8, u16, u32, u64, u128, usize);
+copy_impl!(u8 {
+    // the bytes of `Cow<[u8]>` are passed through the mapper
+    #[inline]
+    fn map_cow_slice<'c>(value: Cow<'c, [u8]>, mapper: &mut impl CowMapper) -> Cow<'c, [u8]> {
+        match value {
+            Cow::Borrowed(value) => mapper.map_bytes(value),
+            Cow::Owned(value) => {
+                // keep the allocation if it's unchanged
+                let result = match mapper.map_bytes(&value) {
+                    Cow::Borrowed(result) if core::ptr::eq(result, value.as_slice()) => None,
+                    result => Some(result.into_owned()),
+                };
+                Cow::Owned(result.unwrap_or(value))
+            }
+        }
+    }
+} {
+    // the bytes of `Cow<[u8]>` are referenced at the same offsets in `new`
+    #[inline]
+    fn rebase_slice(value: &[u8], old: &[u8], new: &'b [u8]) -> Option<&'b [u8]> {
+        rebase_range(old, value).and_then(|range| new.get(range))
+    }
+});
+copy_impl!(u16, u32, u64, u128, usize);
 copy_impl!(i8, i16, i32, i64, i128, isize);
 copy_impl!(f32, f64, bool, char);
 
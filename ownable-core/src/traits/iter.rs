@@ -1,10 +1,30 @@
-use crate::traits::{IntoOwned, ToBorrowed, ToOwned};
+use crate::leak::Leaker;
+use crate::map::CowMapper;
+use crate::traits::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, OwnableVisit,
+    Rebase, ToBorrowed, ToOwned,
+};
+use crate::visit::{FieldPath, PathSegment, Visitor};
 use alloc::collections::{BTreeSet, BinaryHeap, LinkedList, VecDeque};
 use alloc::vec::Vec;
+use core::ops::Range;
 
 // Macro for things to be iterated
 macro_rules! iter_impl {
     ($ty:ident, $($extra:tt)?) => {
+        impl<'a, T> AsBorrowed<'a> for $ty<T>
+        where
+            T: AsBorrowed<'a>,
+          $(  <T as AsBorrowed<'a>>::Borrowed: $extra,)?
+        {
+            type Borrowed = $ty<T::Borrowed>;
+
+            #[inline]
+            fn as_borrowed(&'a self) -> Self::Borrowed {
+                self.iter().map(AsBorrowed::as_borrowed).collect()
+            }
+        }
+
         impl<'a, T> ToBorrowed<'a> for $ty<T>
         where
             T: ToBorrowed<'a> $(+ $extra)?,
@@ -40,6 +60,60 @@ macro_rules! iter_impl {
                 self.into_iter().map(IntoOwned::into_owned).collect()
             }
         }
+
+        impl<T> IntoLeaked for $ty<T>
+        where
+            T: IntoLeaked,
+          $(  <T as IntoLeaked>::Leaked: $extra,)?
+        {
+            type Leaked = $ty<T::Leaked>;
+
+            #[inline]
+            fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+                self.into_iter().map(|v| v.into_leaked(leaker)).collect()
+            }
+        }
+
+        impl<'b, T> Rebase<'b> for $ty<T>
+        where
+            T: Rebase<'b>,
+          $(  <T as Rebase<'b>>::Rebased: $extra,)?
+        {
+            type Rebased = $ty<T::Rebased>;
+
+            #[inline]
+            fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+                self.iter().map(|v| v.rebase(old, new)).collect()
+            }
+        }
+
+        impl<T: ForEachBorrow> ForEachBorrow for $ty<T> {
+            #[inline]
+            fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+                for v in self.iter() {
+                    v.for_each_borrow(f);
+                }
+            }
+        }
+
+        impl<T: OwnableVisit> OwnableVisit for $ty<T> {
+            #[inline]
+            fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+                for (i, v) in self.iter().enumerate() {
+                    v.visit(&path.join(PathSegment::Index(i)), visitor);
+                }
+            }
+        }
+
+        impl<T> MapCows for $ty<T>
+        where
+            T: MapCows $(+ $extra)?,
+        {
+            #[inline]
+            fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+                self.into_iter().map(|v| v.map_cows(mapper)).collect()
+            }
+        }
     };
 }
 
@@ -48,3 +122,44 @@ iter_impl!(VecDeque,);
 iter_impl!(LinkedList,);
 iter_impl!(BinaryHeap, Ord);
 iter_impl!(BTreeSet, Ord);
+
+// Macro for things to be iterated, with or without releasing the excess capacity
+macro_rules! iter_compact_impl {
+    ($ty:ident, $($extra:tt)?) => {
+        impl<T> IntoOwnedCompact for $ty<T>
+        where
+            T: IntoOwnedCompact,
+          $(  <T as IntoOwned>::Owned: $extra,)?
+        {
+            #[inline]
+            fn into_owned_compact(self) -> Self::Owned {
+                self.into_iter()
+                    .map(IntoOwnedCompact::into_owned_compact)
+                    .collect()
+            }
+        }
+    };
+    (shrink $ty:ident, $($extra:tt)?) => {
+        impl<T> IntoOwnedCompact for $ty<T>
+        where
+            T: IntoOwnedCompact,
+          $(  <T as IntoOwned>::Owned: $extra,)?
+        {
+            #[inline]
+            fn into_owned_compact(self) -> Self::Owned {
+                let mut owned: Self::Owned = self
+                    .into_iter()
+                    .map(IntoOwnedCompact::into_owned_compact)
+                    .collect();
+                owned.shrink_to_fit();
+                owned
+            }
+        }
+    };
+}
+
+iter_compact_impl!(shrink Vec,);
+iter_compact_impl!(shrink VecDeque,);
+iter_compact_impl!(LinkedList,);
+iter_compact_impl!(shrink BinaryHeap, Ord);
+iter_compact_impl!(BTreeSet, Ord);
@@ -1,13 +1,28 @@
+//! The traits, which are also used by the derive macros.
+//!
+//! They are also available at the root of the crate.
+
+use crate::leak::{Leakable, Leaker};
+use crate::map::CowMapper;
+use crate::visit::{FieldPath, PathSegment, Visitor};
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
+use core::ops::Range;
 
 mod copy;
 mod iter;
+#[cfg(feature = "rayon")]
+mod rayon;
 #[cfg(feature = "std")]
 mod std;
 
+#[cfg(feature = "rayon")]
+pub use self::rayon::{ParIntoOwned, ParToOwned};
+
 /// Copy the structure and reference the original values.
 ///
 /// This is always a deep copy of the structure.
@@ -19,6 +34,20 @@ pub trait ToBorrowed<'a> {
     fn to_borrowed(&'a self) -> Self;
 }
 
+/// Reference the values of a structure (e.g. from a type generated with `owned_type` or into a
+/// type generated with `view_type`).
+///
+/// This is always a deep copy of the structure.
+pub trait AsBorrowed<'a> {
+    /// The type which references the values.
+    type Borrowed;
+    /// Reference the values of a structure.
+    ///
+    /// This is always a deep copy of the structure.
+    #[must_use]
+    fn as_borrowed(&'a self) -> Self::Borrowed;
+}
+
 /// Copy the structure and clone the original values.
 ///
 /// This is always a deep copy.
@@ -45,6 +74,107 @@ pub trait IntoOwned {
     fn into_owned(self) -> Self::Owned;
 }
 
+/// Copy the structure and clone the original values if it's not owned, excess capacity of the
+/// owned values is released.
+///
+/// This is always a deep copy of the structure.
+pub trait IntoOwnedCompact: IntoOwned {
+    /// Copy the structure and clone the original values if it's not owned, excess capacity of the
+    /// owned values is released.
+    ///
+    /// This is always a deep copy of the structure.
+    #[must_use]
+    fn into_owned_compact(self) -> Self::Owned;
+}
+
+/// Copy the structure and leak the original values (once), which are then referenced.
+///
+/// This is always a deep copy of the structure.
+pub trait IntoLeaked {
+    /// The type after leaking, should be the same as before but with static lifetime.
+    type Leaked;
+    /// Copy the structure and leak the original values (once), which are then referenced.
+    ///
+    /// This is always a deep copy of the structure.
+    #[must_use]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked;
+}
+
+/// Copy the structure and reference the values, which are borrowed from the buffer `old`, at the
+/// same offsets in the buffer `new` (all other values are cloned).
+///
+/// This is always a deep copy of the structure.
+pub trait Rebase<'b> {
+    /// The type after rebasing, should be the same as before but with the lifetime of `new`.
+    type Rebased;
+    /// Copy the structure and reference the values, which are borrowed from the buffer `old`, at
+    /// the same offsets in the buffer `new` (all other values are cloned).
+    ///
+    /// This is always a deep copy of the structure.
+    #[must_use]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased;
+
+    // The same slice in `new`, only the bytes are rebased (all other slices are cloned).
+    #[doc(hidden)]
+    #[inline]
+    fn rebase_slice(_value: &[Self], _old: &[u8], _new: &'b [u8]) -> Option<&'b [Self]>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+/// Report the values which are borrowed.
+pub trait ForEachBorrow {
+    /// Call `f` with the address range of each borrowed value.
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>));
+
+    /// Whether all borrowed values are within `buffer`.
+    fn borrows_from(&self, buffer: &[u8]) -> bool {
+        let buffer = address_range(buffer);
+        let mut result = true;
+        self.for_each_borrow(&mut |range| {
+            result &= buffer.start <= range.start && range.end <= buffer.end;
+        });
+        result
+    }
+}
+
+/// Visit all `Cow` values, see [`visit`](crate::visit).
+pub trait OwnableVisit {
+    /// Call the `visitor` for each `Cow` value, `path` is the path to this value.
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor);
+}
+
+/// Rebuild the structure with each `Cow<str>` and `Cow<[u8]>` passed through a mapper.
+///
+/// All other `Cow` values (e.g. `Cow<[u32]>` or `Cow<Path>`) are unchanged. The keys of maps are
+/// not mapped (the entries would be merged if the mapped keys are equal), but the items of sets
+/// are, thus equal mapped items are merged (e.g. when converting to lowercase).
+pub trait MapCows: Sized {
+    /// Rebuild the structure with each `Cow<str>` and `Cow<[u8]>` passed through the `mapper`.
+    #[must_use]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self;
+
+    // Map a `Cow<[Self]>`, only the bytes are passed through the `mapper`.
+    #[doc(hidden)]
+    #[inline]
+    fn map_cow_slice<'c>(value: Cow<'c, [Self]>, _mapper: &mut impl CowMapper) -> Cow<'c, [Self]>
+    where
+        Self: Clone,
+    {
+        value
+    }
+}
+
+// The address range of a value.
+#[inline]
+fn address_range<T: ?Sized>(value: &T) -> Range<usize> {
+    let start = (value as *const T).cast::<u8>() as usize;
+    start..start + core::mem::size_of_val(value)
+}
+
 // Cow
 
 impl<'a, T: alloc::borrow::ToOwned + ?Sized> ToBorrowed<'a> for Cow<'a, T> {
@@ -72,6 +202,289 @@ impl<T: alloc::borrow::ToOwned + ?Sized + 'static> IntoOwned for Cow<'_, T> {
     }
 }
 
+impl IntoOwnedCompact for Cow<'_, str> {
+    #[inline]
+    fn into_owned_compact(self) -> Cow<'static, str> {
+        let mut owned: String = self.into_owned();
+        owned.shrink_to_fit();
+        Cow::Owned(owned)
+    }
+}
+
+impl<T: Clone + 'static> IntoOwnedCompact for Cow<'_, [T]> {
+    #[inline]
+    fn into_owned_compact(self) -> Cow<'static, [T]> {
+        let mut owned: Vec<T> = self.into_owned();
+        owned.shrink_to_fit();
+        Cow::Owned(owned)
+    }
+}
+
+impl<T: Clone + 'static> IntoOwnedCompact for Cow<'_, T> {
+    #[inline]
+    fn into_owned_compact(self) -> Cow<'static, T> {
+        Cow::Owned(self.into_owned())
+    }
+}
+
+impl<T: Leakable + ?Sized + 'static> IntoLeaked for Cow<'_, T> {
+    type Leaked = Cow<'static, T>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Cow<'static, T> {
+        Cow::Borrowed(T::leak(self, leaker))
+    }
+}
+
+// The range of `value` within `old`, if it's completely within.
+#[inline]
+fn rebase_range(old: &[u8], value: &[u8]) -> Option<Range<usize>> {
+    let start = (value.as_ptr() as usize).checked_sub(old.as_ptr() as usize)?;
+    let end = start + value.len();
+    if end <= old.len() {
+        Some(start..end)
+    } else {
+        None
+    }
+}
+
+impl<'b> Rebase<'b> for Cow<'_, str> {
+    type Rebased = Cow<'b, str>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        if let Cow::Borrowed(value) = self {
+            if let Some(value) = rebase_range(old, value.as_bytes())
+                .and_then(|range| new.get(range))
+                .and_then(|value| core::str::from_utf8(value).ok())
+            {
+                return Cow::Borrowed(value);
+            }
+        }
+        Cow::Owned(String::from(self.as_ref()))
+    }
+}
+
+impl<'b, T: Rebase<'b> + Clone + 'b> Rebase<'b> for Cow<'_, [T]> {
+    type Rebased = Cow<'b, [T]>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        if let Cow::Borrowed(value) = self {
+            if let Some(value) = T::rebase_slice(value, old, new) {
+                return Cow::Borrowed(value);
+            }
+        }
+        Cow::Owned(self.to_vec())
+    }
+}
+
+impl<'b, T: Clone + 'b> Rebase<'b> for Cow<'_, T> {
+    type Rebased = Cow<'b, T>;
+
+    #[inline]
+    fn rebase(&self, _old: &[u8], _new: &'b [u8]) -> Self::Rebased {
+        Cow::Owned(T::clone(self))
+    }
+}
+
+impl<T: alloc::borrow::ToOwned + ?Sized> ForEachBorrow for Cow<'_, T> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        if let Cow::Borrowed(value) = self {
+            f(address_range(*value));
+        }
+    }
+}
+
+impl<T: alloc::borrow::ToOwned + ?Sized> OwnableVisit for Cow<'_, T> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        match self {
+            Cow::Borrowed(value) => visitor.visit_borrowed::<T>(path, value),
+            Cow::Owned(value) => visitor.visit_owned::<T>(path, value),
+        }
+    }
+}
+
+impl MapCows for Cow<'_, str> {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        match self {
+            Cow::Borrowed(value) => mapper.map_str(value),
+            Cow::Owned(value) => {
+                // keep the allocation if it's unchanged
+                let result = match mapper.map_str(&value) {
+                    Cow::Borrowed(result) if core::ptr::eq(result, value.as_str()) => None,
+                    result => Some(result.into_owned()),
+                };
+                Cow::Owned(result.unwrap_or(value))
+            }
+        }
+    }
+}
+
+impl<T: MapCows + Clone> MapCows for Cow<'_, [T]> {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        T::map_cow_slice(self, mapper)
+    }
+}
+
+impl<T: Clone> MapCows for Cow<'_, T> {
+    #[inline]
+    fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+        self
+    }
+}
+
+impl<'a, T: alloc::borrow::ToOwned + ?Sized + 'a> AsBorrowed<'a> for Cow<'_, T> {
+    type Borrowed = Cow<'a, T>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Cow<'a, T> {
+        Cow::Borrowed(self.borrow())
+    }
+}
+
+// &T (the reference is always copied, i.e. the lifetime is not changed)
+
+impl<T: ?Sized> ToBorrowed<'_> for &T {
+    inline_always! {
+        fn to_borrowed(&self) -> Self {
+            self
+        }
+    }
+}
+
+impl<'b, T: ?Sized> ToOwned for &'b T {
+    type Owned = &'b T;
+
+    inline_always! {
+        fn to_owned(&self) -> Self::Owned {
+            self
+        }
+    }
+}
+
+impl<'b, T: ?Sized> IntoOwned for &'b T {
+    type Owned = &'b T;
+
+    inline_always! {
+        fn into_owned(self) -> Self::Owned {
+            self
+        }
+    }
+}
+
+impl<T: ?Sized> IntoOwnedCompact for &T {
+    inline_always! {
+        fn into_owned_compact(self) -> Self::Owned {
+            self
+        }
+    }
+}
+
+impl<'b, T: ?Sized> IntoLeaked for &'b T {
+    type Leaked = &'b T;
+
+    inline_always! {
+        fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {
+            self
+        }
+    }
+}
+
+impl<'b, T: ?Sized> Rebase<'_> for &'b T {
+    type Rebased = &'b T;
+
+    inline_always! {
+        fn rebase(&self, _old: &[u8], _new: &[u8]) -> Self::Rebased {
+            self
+        }
+    }
+}
+
+impl<T: ?Sized> ForEachBorrow for &T {
+    inline_always! {
+        fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+    }
+}
+
+impl<T: ?Sized> OwnableVisit for &T {
+    inline_always! {
+        fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+    }
+}
+
+impl<T: ?Sized> MapCows for &T {
+    inline_always! {
+        fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+            self
+        }
+    }
+}
+
+impl<'b, T: ?Sized> AsBorrowed<'_> for &'b T {
+    type Borrowed = &'b T;
+
+    inline_always! {
+        fn as_borrowed(&self) -> Self::Borrowed {
+            self
+        }
+    }
+}
+
+// &mut T (the reference can only be moved)
+
+impl<'b, T: ?Sized> IntoOwned for &'b mut T {
+    type Owned = &'b mut T;
+
+    inline_always! {
+        fn into_owned(self) -> Self::Owned {
+            self
+        }
+    }
+}
+
+impl<T: ?Sized> IntoOwnedCompact for &mut T {
+    inline_always! {
+        fn into_owned_compact(self) -> Self::Owned {
+            self
+        }
+    }
+}
+
+impl<'b, T: ?Sized> IntoLeaked for &'b mut T {
+    type Leaked = &'b mut T;
+
+    inline_always! {
+        fn into_leaked(self, _leaker: &mut Leaker) -> Self::Leaked {
+            self
+        }
+    }
+}
+
+impl<T: ?Sized> ForEachBorrow for &mut T {
+    inline_always! {
+        fn for_each_borrow(&self, _f: &mut dyn FnMut(Range<usize>)) {}
+    }
+}
+
+impl<T: ?Sized> OwnableVisit for &mut T {
+    inline_always! {
+        fn visit(&self, _path: &FieldPath<'_>, _visitor: &mut impl Visitor) {}
+    }
+}
+
+impl<T: ?Sized> MapCows for &mut T {
+    inline_always! {
+        fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+            self
+        }
+    }
+}
+
 // Option
 
 impl<'a, T: ToBorrowed<'a>> ToBorrowed<'a> for Option<T> {
@@ -99,6 +512,65 @@ impl<T: IntoOwned> IntoOwned for Option<T> {
     }
 }
 
+impl<T: IntoOwnedCompact> IntoOwnedCompact for Option<T> {
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        self.map(IntoOwnedCompact::into_owned_compact)
+    }
+}
+
+impl<T: IntoLeaked> IntoLeaked for Option<T> {
+    type Leaked = Option<T::Leaked>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        self.map(|v| v.into_leaked(leaker))
+    }
+}
+
+impl<'b, T: Rebase<'b>> Rebase<'b> for Option<T> {
+    type Rebased = Option<T::Rebased>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        self.as_ref().map(|v| v.rebase(old, new))
+    }
+}
+
+impl<T: ForEachBorrow> ForEachBorrow for Option<T> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        if let Some(value) = self {
+            value.for_each_borrow(f);
+        }
+    }
+}
+
+impl<T: OwnableVisit> OwnableVisit for Option<T> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        if let Some(value) = self {
+            value.visit(path, visitor);
+        }
+    }
+}
+
+impl<T: MapCows> MapCows for Option<T> {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        self.map(|v| v.map_cows(mapper))
+    }
+}
+
+impl<'a, T: AsBorrowed<'a>> AsBorrowed<'a> for Option<T> {
+    type Borrowed = Option<T::Borrowed>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        self.as_ref().map(AsBorrowed::as_borrowed)
+    }
+}
+
 // Box<T>
 
 impl<'a, T: ToBorrowed<'a>> ToBorrowed<'a> for Box<T> {
@@ -126,6 +598,61 @@ impl<T: IntoOwned> IntoOwned for Box<T> {
     }
 }
 
+impl<T: IntoOwnedCompact> IntoOwnedCompact for Box<T> {
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        Box::new((*self).into_owned_compact())
+    }
+}
+
+impl<T: IntoLeaked> IntoLeaked for Box<T> {
+    type Leaked = Box<T::Leaked>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        Box::new((*self).into_leaked(leaker))
+    }
+}
+
+impl<'b, T: Rebase<'b>> Rebase<'b> for Box<T> {
+    type Rebased = Box<T::Rebased>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        Box::new(self.as_ref().rebase(old, new))
+    }
+}
+
+impl<T: ForEachBorrow> ForEachBorrow for Box<T> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        self.as_ref().for_each_borrow(f);
+    }
+}
+
+impl<T: OwnableVisit> OwnableVisit for Box<T> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        self.as_ref().visit(path, visitor);
+    }
+}
+
+impl<T: MapCows> MapCows for Box<T> {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        Box::new((*self).map_cows(mapper))
+    }
+}
+
+impl<'a, T: AsBorrowed<'a>> AsBorrowed<'a> for Box<T> {
+    type Borrowed = Box<T::Borrowed>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        Box::new(self.as_ref().as_borrowed())
+    }
+}
+
 // Box<[T]>
 
 impl<'a, T: ToBorrowed<'a>> ToBorrowed<'a> for Box<[T]> {
@@ -156,6 +683,74 @@ impl<T: IntoOwned> IntoOwned for Box<[T]> {
     }
 }
 
+impl<T: IntoOwnedCompact> IntoOwnedCompact for Box<[T]> {
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        self.into_vec()
+            .into_iter()
+            .map(IntoOwnedCompact::into_owned_compact)
+            .collect()
+    }
+}
+
+impl<T: IntoLeaked> IntoLeaked for Box<[T]> {
+    type Leaked = Box<[T::Leaked]>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        self.into_vec()
+            .into_iter()
+            .map(|v| v.into_leaked(leaker))
+            .collect()
+    }
+}
+
+impl<'b, T: Rebase<'b>> Rebase<'b> for Box<[T]> {
+    type Rebased = Box<[T::Rebased]>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        self.iter().map(|v| v.rebase(old, new)).collect()
+    }
+}
+
+impl<T: ForEachBorrow> ForEachBorrow for Box<[T]> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        for v in self.iter() {
+            v.for_each_borrow(f);
+        }
+    }
+}
+
+impl<T: OwnableVisit> OwnableVisit for Box<[T]> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        for (i, v) in self.iter().enumerate() {
+            v.visit(&path.join(PathSegment::Index(i)), visitor);
+        }
+    }
+}
+
+impl<T: MapCows> MapCows for Box<[T]> {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        self.into_vec()
+            .into_iter()
+            .map(|v| v.map_cows(mapper))
+            .collect()
+    }
+}
+
+impl<'a, T: AsBorrowed<'a>> AsBorrowed<'a> for Box<[T]> {
+    type Borrowed = Box<[T::Borrowed]>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        self.iter().map(AsBorrowed::as_borrowed).collect()
+    }
+}
+
 // BTreeMap
 
 impl<'a, K, V> ToBorrowed<'a> for BTreeMap<K, V>
@@ -203,6 +798,102 @@ where
     }
 }
 
+impl<K, V> IntoOwnedCompact for BTreeMap<K, V>
+where
+    K: IntoOwnedCompact,
+    <K as IntoOwned>::Owned: Ord,
+    V: IntoOwnedCompact,
+{
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        self.into_iter()
+            .map(|(k, v)| {
+                (
+                    IntoOwnedCompact::into_owned_compact(k),
+                    IntoOwnedCompact::into_owned_compact(v),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<K, V> IntoLeaked for BTreeMap<K, V>
+where
+    K: IntoLeaked,
+    <K as IntoLeaked>::Leaked: Ord,
+    V: IntoLeaked,
+{
+    type Leaked = BTreeMap<K::Leaked, V::Leaked>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        self.into_iter()
+            .map(|(k, v)| (k.into_leaked(leaker), v.into_leaked(leaker)))
+            .collect()
+    }
+}
+
+impl<'b, K, V> Rebase<'b> for BTreeMap<K, V>
+where
+    K: Rebase<'b>,
+    <K as Rebase<'b>>::Rebased: Ord,
+    V: Rebase<'b>,
+{
+    type Rebased = BTreeMap<K::Rebased, V::Rebased>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        self.iter()
+            .map(|(k, v)| (k.rebase(old, new), v.rebase(old, new)))
+            .collect()
+    }
+}
+
+impl<K: ForEachBorrow, V: ForEachBorrow> ForEachBorrow for BTreeMap<K, V> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        for (k, v) in self {
+            k.for_each_borrow(f);
+            v.for_each_borrow(f);
+        }
+    }
+}
+
+impl<K: OwnableVisit, V: OwnableVisit> OwnableVisit for BTreeMap<K, V> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        for (i, (k, v)) in self.iter().enumerate() {
+            k.visit(&path.join(PathSegment::Key(i)), visitor);
+            v.visit(&path.join(PathSegment::Value(i)), visitor);
+        }
+    }
+}
+
+impl<K: Ord, V: MapCows> MapCows for BTreeMap<K, V> {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        self.into_iter()
+            .map(|(k, v)| (k, v.map_cows(mapper)))
+            .collect()
+    }
+}
+
+impl<'a, K, V> AsBorrowed<'a> for BTreeMap<K, V>
+where
+    K: AsBorrowed<'a>,
+    <K as AsBorrowed<'a>>::Borrowed: Ord,
+    V: AsBorrowed<'a>,
+{
+    type Borrowed = BTreeMap<K::Borrowed, V::Borrowed>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        self.iter()
+            .map(|(k, v)| (k.as_borrowed(), v.as_borrowed()))
+            .collect()
+    }
+}
+
 // Arrays
 
 impl<'a, T, const N: usize> ToBorrowed<'a> for [T; N]
@@ -239,13 +930,90 @@ where
     }
 }
 
+impl<T, const N: usize> IntoOwnedCompact for [T; N]
+where
+    T: IntoOwnedCompact,
+{
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        self.map(IntoOwnedCompact::into_owned_compact)
+    }
+}
+
+impl<T, const N: usize> IntoLeaked for [T; N]
+where
+    T: IntoLeaked,
+{
+    type Leaked = [T::Leaked; N];
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        self.map(|v| v.into_leaked(leaker))
+    }
+}
+
+impl<'b, T, const N: usize> Rebase<'b> for [T; N]
+where
+    T: Rebase<'b>,
+{
+    type Rebased = [T::Rebased; N];
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        core::array::from_fn(|i| self[i].rebase(old, new))
+    }
+}
+
+impl<T: ForEachBorrow, const N: usize> ForEachBorrow for [T; N] {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        for v in self {
+            v.for_each_borrow(f);
+        }
+    }
+}
+
+impl<T: OwnableVisit, const N: usize> OwnableVisit for [T; N] {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        for (i, v) in self.iter().enumerate() {
+            v.visit(&path.join(PathSegment::Index(i)), visitor);
+        }
+    }
+}
+
+impl<T: MapCows, const N: usize> MapCows for [T; N] {
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        self.map(|v| v.map_cows(mapper))
+    }
+}
+
+impl<'a, T: AsBorrowed<'a>, const N: usize> AsBorrowed<'a> for [T; N] {
+    type Borrowed = [T::Borrowed; N];
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        core::array::from_fn(|i| self[i].as_borrowed())
+    }
+}
+
 // Tuples
 
 macro_rules! tuple_impls {
-    ($($(#[$attrs:meta])?($($n:tt $name:ident)+),)+) => {
+    ($($(#[$attrs:meta])*($($n:tt $name:ident)+),)+) => {
         $(
+            impl<'a, $($name: AsBorrowed<'a>),+> AsBorrowed<'a> for ($($name,)+) {
+                type Borrowed = ($($name::Borrowed,)+);
+
+                $(#[$attrs])*
+                fn as_borrowed(&'a self) -> Self::Borrowed {
+                    ($(self.$n.as_borrowed(),)+)
+                }
+            }
+
             impl<'a, $($name: ToBorrowed<'a>),+> ToBorrowed<'a> for ($($name,)+) {
-                $(#[$attrs])?
+                $(#[$attrs])*
                 fn to_borrowed(&'a self) -> Self {
                     ($(self.$n.to_borrowed(),)+)
                 }
@@ -254,7 +1022,7 @@ macro_rules! tuple_impls {
             impl<$($name: ToOwned),+> ToOwned for ($($name,)+) {
                 type Owned = ($($name::Owned,)+);
 
-                $(#[$attrs])?
+                $(#[$attrs])*
                 fn to_owned(&self) -> Self::Owned where {
                     ($(self.$n.to_owned(),)+)
                 }
@@ -263,17 +1031,65 @@ macro_rules! tuple_impls {
             impl<$($name: IntoOwned),+> IntoOwned for ($($name,)+) {
                 type Owned = ($($name::Owned,)+);
 
-                $(#[$attrs])?
+                $(#[$attrs])*
                 fn into_owned(self) -> Self::Owned {
                     ($(IntoOwned::into_owned(self.$n),)+)
                 }
             }
+
+            impl<$($name: IntoOwnedCompact),+> IntoOwnedCompact for ($($name,)+) {
+                $(#[$attrs])*
+                fn into_owned_compact(self) -> Self::Owned {
+                    ($(IntoOwnedCompact::into_owned_compact(self.$n),)+)
+                }
+            }
+
+            impl<$($name: IntoLeaked),+> IntoLeaked for ($($name,)+) {
+                type Leaked = ($($name::Leaked,)+);
+
+                $(#[$attrs])*
+                fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+                    ($(IntoLeaked::into_leaked(self.$n, leaker),)+)
+                }
+            }
+
+            impl<'b, $($name: Rebase<'b>),+> Rebase<'b> for ($($name,)+) {
+                type Rebased = ($($name::Rebased,)+);
+
+                $(#[$attrs])*
+                fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+                    ($(self.$n.rebase(old, new),)+)
+                }
+            }
+
+            impl<$($name: ForEachBorrow),+> ForEachBorrow for ($($name,)+) {
+                $(#[$attrs])*
+                fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+                    $(self.$n.for_each_borrow(f);)+
+                }
+            }
+
+            impl<$($name: OwnableVisit),+> OwnableVisit for ($($name,)+) {
+                $(#[$attrs])*
+                fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+                    $(self.$n.visit(&path.join(PathSegment::Field(stringify!($n))), visitor);)+
+                }
+            }
+
+            impl<$($name: MapCows),+> MapCows for ($($name,)+) {
+                $(#[$attrs])*
+                fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+                    ($(self.$n.map_cows(mapper),)+)
+                }
+            }
         )+
     };
 }
 
 tuple_impls! {
-    #[inline(always)] (0 T0),
+    #[cfg_attr(not(feature = "no_inline_always"), inline(always))]
+    #[cfg_attr(feature = "no_inline_always", inline)]
+    (0 T0),
     #[inline] (0 T0 1 T1),
     #[inline] (0 T0 1 T1 2 T2),
     (0 T0 1 T1 2 T2 3 T3),
@@ -0,0 +1,186 @@
+use crate::traits::{IntoOwned, ToOwned};
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+
+// The conversion of an element, with the depth and limit of `stack` passed on to the workers.
+#[cfg(feature = "stack_safe")]
+#[inline]
+fn convert<T, R>(f: impl Fn(T) -> R + Send + Sync) -> impl Fn(T) -> R + Send + Sync {
+    let context = crate::stack::Context::current();
+    move |value| context.run(|| f(value))
+}
+
+#[cfg(not(feature = "stack_safe"))]
+#[inline]
+fn convert<T, R>(f: impl Fn(T) -> R + Send + Sync) -> impl Fn(T) -> R + Send + Sync {
+    f
+}
+
+/// Copy the structure and clone the original values, the elements are converted in parallel.
+///
+/// This is always a deep copy.
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub trait ParToOwned: ToOwned {
+    /// Copy the structure and clone the original values, the elements are converted in parallel.
+    ///
+    /// This is always a deep copy.
+    #[must_use]
+    fn par_to_owned(&self) -> Self::Owned;
+}
+
+/// Copy the structure and clone the original values if it's not owned, the elements are
+/// converted in parallel.
+///
+/// This is always a deep copy of the structure.
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+pub trait ParIntoOwned: IntoOwned {
+    /// Copy the structure and clone the original values if it's not owned, the elements are
+    /// converted in parallel.
+    ///
+    /// This is always a deep copy of the structure.
+    #[must_use]
+    fn par_into_owned(self) -> Self::Owned;
+}
+
+// Vec
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<T> ParToOwned for Vec<T>
+where
+    T: ToOwned + Sync,
+    <T as ToOwned>::Owned: Send,
+{
+    #[inline]
+    fn par_to_owned(&self) -> Self::Owned {
+        self.par_iter().map(convert(ToOwned::to_owned)).collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<T> ParIntoOwned for Vec<T>
+where
+    T: IntoOwned + Send,
+    <T as IntoOwned>::Owned: Send,
+{
+    #[inline]
+    fn par_into_owned(self) -> Self::Owned {
+        self.into_par_iter()
+            .map(convert(IntoOwned::into_owned))
+            .collect()
+    }
+}
+
+// Box<[T]>
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<T> ParToOwned for Box<[T]>
+where
+    T: ToOwned + Sync,
+    <T as ToOwned>::Owned: Send,
+{
+    #[inline]
+    fn par_to_owned(&self) -> Self::Owned {
+        self.par_iter()
+            .map(convert(ToOwned::to_owned))
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<T> ParIntoOwned for Box<[T]>
+where
+    T: IntoOwned + Send,
+    <T as IntoOwned>::Owned: Send,
+{
+    #[inline]
+    fn par_into_owned(self) -> Self::Owned {
+        self.into_vec()
+            .into_par_iter()
+            .map(convert(IntoOwned::into_owned))
+            .collect::<Vec<_>>()
+            .into_boxed_slice()
+    }
+}
+
+// HashMap
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<K, V, S> ParToOwned for HashMap<K, V, S>
+where
+    K: ToOwned + Eq + Hash + Sync,
+    <K as ToOwned>::Owned: Eq + Hash + Send,
+    V: ToOwned + Sync,
+    <V as ToOwned>::Owned: Send,
+    S: BuildHasher + Default + Send + Sync,
+{
+    #[inline]
+    fn par_to_owned(&self) -> Self::Owned {
+        self.par_iter()
+            .map(convert(|(k, v)| {
+                (ToOwned::to_owned(k), ToOwned::to_owned(v))
+            }))
+            .collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<K, V, S> ParIntoOwned for HashMap<K, V, S>
+where
+    K: IntoOwned + Eq + Hash + Send,
+    <K as IntoOwned>::Owned: Eq + Hash + Send,
+    V: IntoOwned + Send,
+    <V as IntoOwned>::Owned: Send,
+    S: BuildHasher + Default + Send,
+{
+    #[inline]
+    fn par_into_owned(self) -> Self::Owned {
+        self.into_par_iter()
+            .map(convert(|(k, v)| {
+                (IntoOwned::into_owned(k), IntoOwned::into_owned(v))
+            }))
+            .collect()
+    }
+}
+
+// BTreeMap
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<K, V> ParToOwned for BTreeMap<K, V>
+where
+    K: ToOwned + Ord + Sync,
+    <K as ToOwned>::Owned: Ord + Send,
+    V: ToOwned + Sync,
+    <V as ToOwned>::Owned: Send,
+{
+    #[inline]
+    fn par_to_owned(&self) -> Self::Owned {
+        self.par_iter()
+            .map(convert(|(k, v)| {
+                (ToOwned::to_owned(k), ToOwned::to_owned(v))
+            }))
+            .collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "rayon")))]
+impl<K, V> ParIntoOwned for BTreeMap<K, V>
+where
+    K: IntoOwned + Ord + Send,
+    <K as IntoOwned>::Owned: Ord + Send,
+    V: IntoOwned + Send,
+    <V as IntoOwned>::Owned: Send,
+{
+    #[inline]
+    fn par_into_owned(self) -> Self::Owned {
+        self.into_par_iter()
+            .map(convert(|(k, v)| {
+                (IntoOwned::into_owned(k), IntoOwned::into_owned(v))
+            }))
+            .collect()
+    }
+}
@@ -1,6 +1,134 @@
-use crate::traits::{IntoOwned, ToBorrowed, ToOwned};
+use crate::leak::{Leakable, Leaker};
+use crate::map::CowMapper;
+use crate::traits::rebase_range;
+use crate::traits::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, OwnableVisit,
+    Rebase, ToBorrowed, ToOwned,
+};
+use crate::visit::{FieldPath, PathSegment, Visitor};
+use alloc::borrow::Cow;
+use core::ops::Range;
 use std::collections::{HashMap, HashSet};
+use std::ffi::{CStr, CString, OsStr, OsString};
 use std::hash::{BuildHasher, Hash};
+use std::path::{Path, PathBuf};
+
+// Cow
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl IntoOwnedCompact for Cow<'_, CStr> {
+    #[inline]
+    fn into_owned_compact(self) -> Cow<'static, CStr> {
+        // a `CString` is a boxed slice, thus it never has excess capacity
+        Cow::Owned(self.into_owned())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl IntoOwnedCompact for Cow<'_, OsStr> {
+    #[inline]
+    fn into_owned_compact(self) -> Cow<'static, OsStr> {
+        let mut owned: OsString = self.into_owned();
+        owned.shrink_to_fit();
+        Cow::Owned(owned)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl IntoOwnedCompact for Cow<'_, Path> {
+    #[inline]
+    fn into_owned_compact(self) -> Cow<'static, Path> {
+        let mut owned: PathBuf = self.into_owned();
+        owned.shrink_to_fit();
+        Cow::Owned(owned)
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Leakable for CStr {
+    #[inline]
+    fn leak(value: Cow<'_, Self>, _leaker: &mut Leaker) -> &'static Self {
+        Box::leak(value.into_owned().into_boxed_c_str())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Leakable for OsStr {
+    #[inline]
+    fn leak(value: Cow<'_, Self>, _leaker: &mut Leaker) -> &'static Self {
+        Box::leak(value.into_owned().into_boxed_os_str())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl Leakable for Path {
+    #[inline]
+    fn leak(value: Cow<'_, Self>, _leaker: &mut Leaker) -> &'static Self {
+        Box::leak(value.into_owned().into_boxed_path())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl MapCows for Cow<'_, CStr> {
+    #[inline]
+    fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+        self
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl MapCows for Cow<'_, OsStr> {
+    #[inline]
+    fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+        self
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl MapCows for Cow<'_, Path> {
+    #[inline]
+    fn map_cows(self, _mapper: &mut impl CowMapper) -> Self {
+        self
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'b> Rebase<'b> for Cow<'_, CStr> {
+    type Rebased = Cow<'b, CStr>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        if let Cow::Borrowed(value) = self {
+            if let Some(value) = rebase_range(old, value.to_bytes_with_nul())
+                .and_then(|range| new.get(range))
+                .and_then(|value| CStr::from_bytes_with_nul(value).ok())
+            {
+                return Cow::Borrowed(value);
+            }
+        }
+        Cow::Owned(CString::from(self.as_ref()))
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'b> Rebase<'b> for Cow<'_, OsStr> {
+    type Rebased = Cow<'b, OsStr>;
+
+    #[inline]
+    fn rebase(&self, _old: &[u8], _new: &'b [u8]) -> Self::Rebased {
+        Cow::Owned(self.to_os_string())
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'b> Rebase<'b> for Cow<'_, Path> {
+    type Rebased = Cow<'b, Path>;
+
+    #[inline]
+    fn rebase(&self, _old: &[u8], _new: &'b [u8]) -> Self::Rebased {
+        Cow::Owned(self.to_path_buf())
+    }
+}
 
 // HashSet
 
@@ -46,6 +174,101 @@ where
     }
 }
 
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T, S> IntoOwnedCompact for HashSet<T, S>
+where
+    T: IntoOwnedCompact,
+    <T as IntoOwned>::Owned: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        let mut owned: Self::Owned = self
+            .into_iter()
+            .map(IntoOwnedCompact::into_owned_compact)
+            .collect();
+        owned.shrink_to_fit();
+        owned
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T, S> IntoLeaked for HashSet<T, S>
+where
+    T: IntoLeaked,
+    <T as IntoLeaked>::Leaked: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Leaked = HashSet<T::Leaked, S>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        self.into_iter().map(|v| v.into_leaked(leaker)).collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'b, T, S> Rebase<'b> for HashSet<T, S>
+where
+    T: Rebase<'b>,
+    <T as Rebase<'b>>::Rebased: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Rebased = HashSet<T::Rebased, S>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        self.iter().map(|v| v.rebase(old, new)).collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: ForEachBorrow, S> ForEachBorrow for HashSet<T, S> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        for v in self {
+            v.for_each_borrow(f);
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T: OwnableVisit, S> OwnableVisit for HashSet<T, S> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        for (i, v) in self.iter().enumerate() {
+            v.visit(&path.join(PathSegment::Index(i)), visitor);
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<T, S> MapCows for HashSet<T, S>
+where
+    T: MapCows + Eq + Hash,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        self.into_iter().map(|v| v.map_cows(mapper)).collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'a, T, S> AsBorrowed<'a> for HashSet<T, S>
+where
+    T: AsBorrowed<'a>,
+    <T as AsBorrowed<'a>>::Borrowed: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Borrowed = HashSet<T::Borrowed, S>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        self.iter().map(AsBorrowed::as_borrowed).collect()
+    }
+}
+
 // HashMap
 
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
@@ -98,3 +321,118 @@ where
             .collect()
     }
 }
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V, S> IntoOwnedCompact for HashMap<K, V, S>
+where
+    K: IntoOwnedCompact,
+    <K as IntoOwned>::Owned: Eq + Hash,
+    V: IntoOwnedCompact,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn into_owned_compact(self) -> Self::Owned {
+        let mut owned: Self::Owned = self
+            .into_iter()
+            .map(|(k, v)| {
+                (
+                    IntoOwnedCompact::into_owned_compact(k),
+                    IntoOwnedCompact::into_owned_compact(v),
+                )
+            })
+            .collect();
+        owned.shrink_to_fit();
+        owned
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V, S> IntoLeaked for HashMap<K, V, S>
+where
+    K: IntoLeaked,
+    <K as IntoLeaked>::Leaked: Eq + Hash,
+    V: IntoLeaked,
+    S: BuildHasher + Default,
+{
+    type Leaked = HashMap<K::Leaked, V::Leaked, S>;
+
+    #[inline]
+    fn into_leaked(self, leaker: &mut Leaker) -> Self::Leaked {
+        self.into_iter()
+            .map(|(k, v)| (k.into_leaked(leaker), v.into_leaked(leaker)))
+            .collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'b, K, V, S> Rebase<'b> for HashMap<K, V, S>
+where
+    K: Rebase<'b>,
+    <K as Rebase<'b>>::Rebased: Eq + Hash,
+    V: Rebase<'b>,
+    S: BuildHasher + Default,
+{
+    type Rebased = HashMap<K::Rebased, V::Rebased, S>;
+
+    #[inline]
+    fn rebase(&self, old: &[u8], new: &'b [u8]) -> Self::Rebased {
+        self.iter()
+            .map(|(k, v)| (k.rebase(old, new), v.rebase(old, new)))
+            .collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K: ForEachBorrow, V: ForEachBorrow, S> ForEachBorrow for HashMap<K, V, S> {
+    #[inline]
+    fn for_each_borrow(&self, f: &mut dyn FnMut(Range<usize>)) {
+        for (k, v) in self {
+            k.for_each_borrow(f);
+            v.for_each_borrow(f);
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K: OwnableVisit, V: OwnableVisit, S> OwnableVisit for HashMap<K, V, S> {
+    #[inline]
+    fn visit(&self, path: &FieldPath<'_>, visitor: &mut impl Visitor) {
+        for (i, (k, v)) in self.iter().enumerate() {
+            k.visit(&path.join(PathSegment::Key(i)), visitor);
+            v.visit(&path.join(PathSegment::Value(i)), visitor);
+        }
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<K, V, S> MapCows for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    V: MapCows,
+    S: BuildHasher + Default,
+{
+    #[inline]
+    fn map_cows(self, mapper: &mut impl CowMapper) -> Self {
+        self.into_iter()
+            .map(|(k, v)| (k, v.map_cows(mapper)))
+            .collect()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<'a, K, V, S> AsBorrowed<'a> for HashMap<K, V, S>
+where
+    K: AsBorrowed<'a>,
+    <K as AsBorrowed<'a>>::Borrowed: Eq + Hash,
+    V: AsBorrowed<'a>,
+    S: BuildHasher + Default,
+{
+    type Borrowed = HashMap<K::Borrowed, V::Borrowed, S>;
+
+    #[inline]
+    fn as_borrowed(&'a self) -> Self::Borrowed {
+        self.iter()
+            .map(|(k, v)| (k.as_borrowed(), v.as_borrowed()))
+            .collect()
+    }
+}
@@ -0,0 +1,123 @@
+//! Visit all `Cow` values within a structure, see [`OwnableVisit`](crate::OwnableVisit).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable_core::OwnableVisit;
+//! # use ownable_core::visit::{FieldPath, Visitor};
+//! #[derive(Default)]
+//! struct Count {
+//!     borrowed: usize,
+//!     owned: usize,
+//! }
+//!
+//! impl Visitor for Count {
+//!     fn visit_borrowed<T: ToOwned + ?Sized>(&mut self, _path: &FieldPath<'_>, _value: &T) {
+//!         self.borrowed += 1;
+//!     }
+//!
+//!     fn visit_owned<T: ToOwned + ?Sized>(&mut self, _path: &FieldPath<'_>, _value: &T::Owned) {
+//!         self.owned += 1;
+//!     }
+//! }
+//!
+//! let value: Vec<Cow<'_, str>> = vec![Cow::Borrowed("borrowed"), Cow::Owned("owned".into())];
+//! let mut count = Count::default();
+//! value.visit(&FieldPath::root(), &mut count);
+//! assert_eq!((count.borrowed, count.owned), (1, 1));
+//! ```
+
+use alloc::borrow::ToOwned;
+use core::fmt::{Display, Formatter};
+
+/// The callbacks for each `Cow` value.
+pub trait Visitor {
+    /// Called for each `Cow::Borrowed`.
+    fn visit_borrowed<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, value: &T) {
+        let _ = (path, value);
+    }
+
+    /// Called for each `Cow::Owned`.
+    fn visit_owned<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, value: &T::Owned) {
+        let _ = (path, value);
+    }
+}
+
+/// One step on the way from the visited value to a `Cow`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A field of a struct, a variant or a tuple.
+    Field(&'static str),
+    /// A variant of an enum.
+    Variant(&'static str),
+    /// The n-th element of a sequence or set.
+    Index(usize),
+    /// The key of the n-th entry of a map.
+    Key(usize),
+    /// The value of the n-th entry of a map.
+    Value(usize),
+}
+
+/// The path from the visited value to a `Cow`, which is built on the stack.
+///
+/// It's displayed similar to rust code, e.g. `field[3].inner`.
+#[derive(Clone, Copy, Debug)]
+pub struct FieldPath<'a> {
+    parent: Option<&'a FieldPath<'a>>,
+    segment: Option<PathSegment>,
+}
+
+impl<'a> FieldPath<'a> {
+    /// The empty path.
+    #[must_use]
+    pub const fn root() -> Self {
+        FieldPath {
+            parent: None,
+            segment: None,
+        }
+    }
+
+    /// The path with one more segment.
+    #[must_use]
+    pub const fn join(&'a self, segment: PathSegment) -> FieldPath<'a> {
+        FieldPath {
+            parent: Some(self),
+            segment: Some(segment),
+        }
+    }
+
+    /// The last segment, `None` for the root.
+    #[must_use]
+    pub const fn segment(&self) -> Option<PathSegment> {
+        self.segment
+    }
+
+    /// The path without the last segment, `None` for the root.
+    #[must_use]
+    pub const fn parent(&self) -> Option<&'a FieldPath<'a>> {
+        self.parent
+    }
+}
+
+impl Display for FieldPath<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let segment = match self.segment {
+            Some(segment) => segment,
+            None => return Ok(()),
+        };
+        let parent = self.parent.filter(|p| p.segment.is_some());
+        if let Some(parent) = parent {
+            parent.fmt(f)?;
+        }
+        match segment {
+            PathSegment::Field(name) | PathSegment::Variant(name) => {
+                if parent.is_some() {
+                    f.write_str(".")?;
+                }
+                f.write_str(name)
+            }
+            PathSegment::Index(i) => write!(f, "[{i}]"),
+            PathSegment::Key(i) => write!(f, "[{i}].key"),
+            PathSegment::Value(i) => write!(f, "[{i}].value"),
+        }
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "derive")]
+
+use ownable_core::{IntoOwned, Ownable, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+// The derive macros are used through `ownable-core` (with the feature `derive`).
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+#[ownable(crate = "ownable_core")]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    #[ownable(clone)]
+    owned: String,
+}
+
+#[derive(Debug, PartialEq, Ownable)]
+#[ownable(crate = "ownable_core")]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, [u8]>),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&value),
+        owned: value.clone(),
+    };
+    let v1: Test<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: Test<'static> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: Test<'static> = v0.into_owned();
+    assert_eq!(v2, v3);
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<'_> = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+    };
+    let v1: TestEnum<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: TestEnum<'static> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: TestEnum<'static> = TestEnum::Unnamed(Cow::Borrowed(value.as_bytes())).into_owned();
+    assert_eq!(v3, TestEnum::Unnamed(Cow::Borrowed(b"value")));
+}
@@ -2,49 +2,137 @@
 #![allow(clippy::needless_continue)]
 
 use crate::derive::Derive;
-use darling::util::SpannedValue;
+use crate::mode::Mode;
+use darling::ast::NestedMeta;
+use darling::util::{Override, SpannedValue};
 use darling::{Error, FromAttributes, FromDeriveInput, FromMeta};
-use proc_macro2::{Ident, Span};
-use syn::{GenericParam, Generics, Lifetime};
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::visit::Visit;
+use syn::{
+    parse_quote, Data, DeriveInput, Expr, GenericArgument, GenericParam, Generics, Lifetime, Meta,
+    Path, PathArguments, Type, TypePath, TypeReference, Visibility, WhereClause, WherePredicate,
+};
 
 //
 // DeriveAttribute
 //
 
 #[derive(Default, Debug, FromDeriveInput)]
-#[darling(default, attributes(ownable), supports(struct_any, enum_any))]
+#[darling(default, attributes(ownable))]
 pub(crate) struct DeriveAttribute {
-    #[darling(default, rename = "reference")]
-    reference_lifetime: SpannedValue<LifetimesAttribute>,
+    #[darling(rename = "reference")]
+    reference_lifetime: Option<SpannedValue<LifetimesAttribute>>,
+    #[darling(skip)]
+    reference_lifetime_inferred: Vec<String>,
+    #[darling(rename = "keep")]
+    keep_lifetime: Option<SpannedValue<LifetimesAttribute>>,
     pub(crate) clone: Option<bool>,
-    pub(crate) function: Option<bool>,
+    pub(crate) function: FunctionAttribute,
+    pub(crate) inline: InlineAttribute,
+    pub(crate) stack_safe: Option<bool>,
+    pub(crate) owned_type: Option<Ident>,
+    pub(crate) view_type: Option<Ident>,
+    pub(crate) bound: Option<BoundAttribute>,
+    #[darling(rename = "crate")]
+    krate: Option<Path>,
+    into_owned: Option<SpannedValue<bool>>,
+    to_borrowed: Option<SpannedValue<bool>>,
+    to_owned: Option<SpannedValue<bool>>,
 }
 
 impl DeriveAttribute {
-    pub(crate) fn is_reference_lifetime(&self, ident: &Ident) -> bool {
-        self.reference_lifetime.0.iter().any(|kl| ident == kl)
+    // The path of the crate `ownable` (or a re-export), which is used by the generated code.
+    pub(crate) fn krate(&self) -> Path {
+        self.krate
+            .clone()
+            .unwrap_or_else(|| parse_quote!(::ownable))
     }
 
-    pub(crate) fn new_lifetime(&self, derive: &mut Derive) -> Lifetime {
-        const TRY_LIFETIME: &str = "ownable";
+    // The flag which selects whether the mode is derived by `Ownable` (e.g. `to_owned = false`).
+    pub(crate) fn derived_flag(&self, mode: Mode) -> Option<&SpannedValue<bool>> {
+        match mode {
+            Mode::IntoOwned => self.into_owned.as_ref(),
+            Mode::ToBorrowed => self.to_borrowed.as_ref(),
+            Mode::ToOwned => self.to_owned.as_ref(),
+            _ => None,
+        }
+    }
 
-        for l in TRY_LIFETIME
-            .char_indices()
-            .map(move |(pos, _)| &TRY_LIFETIME[..=pos])
-        {
-            if !self.reference_lifetime.0.iter().any(|kl| l == kl) {
-                return Lifetime::new(&format!("'{l}"), Span::call_site());
-            }
+    // Whether the mode is derived by `Ownable` (it can be disabled, e.g. with `to_owned = false`).
+    pub(crate) fn is_derived(&self, mode: Mode) -> bool {
+        self.derived_flag(mode).map_or(true, |flag| **flag)
+    }
+
+    // The lifetimes used solely for references, either set with `reference` or inferred.
+    fn reference_lifetimes(&self) -> &[String] {
+        match &self.reference_lifetime {
+            Some(reference_lifetime) => &reference_lifetime.0,
+            None => &self.reference_lifetime_inferred,
+        }
+    }
+
+    // The lifetimes which are not changed, the reference lifetimes and the ones set with `keep`.
+    fn kept_lifetimes(&self) -> impl Iterator<Item = &String> {
+        let keep = self.keep_lifetime.as_ref().map(|k| k.0.as_slice());
+        self.reference_lifetimes()
+            .iter()
+            .chain(keep.unwrap_or_default())
+    }
+
+    pub(crate) fn is_kept_lifetime(&self, ident: &Ident) -> bool {
+        self.kept_lifetimes().any(|kl| ident == kl)
+    }
+
+    // Whether the type uses lifetimes set with `keep` and otherwise only kept lifetimes, then the
+    // value is passed through (moved or cloned).
+    pub(crate) fn is_kept_type(&self, ty: &Type) -> bool {
+        let keep = match &self.keep_lifetime {
+            Some(keep) => keep,
+            None => return false,
+        };
+        let mut lifetimes = Lifetimes::default();
+        lifetimes.visit_type(ty);
+        lifetimes
+            .0
+            .iter()
+            .any(|l| keep.0.iter().any(|k| l.ident == k))
+            && (lifetimes.0.iter()).all(|l| l.ident == "static" || self.is_kept_lifetime(&l.ident))
+    }
+
+    // Infer the reference lifetimes (if not set): All which are used by references but nowhere
+    // else (e.g. not by `Cow` or other types, even if those use them solely for references).
+    pub(crate) fn infer_reference_lifetimes(&mut self, input: &DeriveInput) {
+        if self.reference_lifetime.is_some() {
+            return;
         }
+        let mut uses = LifetimeUses {
+            ident: &input.ident,
+            lifetimes: input.generics.lifetimes().map(|l| &l.lifetime).collect(),
+            reference: Vec::new(),
+            other: Vec::new(),
+        };
+        match &input.data {
+            Data::Struct(data) => data.fields.iter().for_each(|f| uses.visit_type(&f.ty)),
+            Data::Enum(data) => (data.variants.iter())
+                .flat_map(|v| &v.fields)
+                .for_each(|f| uses.visit_type(&f.ty)),
+            Data::Union(data) => (data.fields.named.iter()).for_each(|f| uses.visit_type(&f.ty)),
+        }
+        self.reference_lifetime_inferred = input
+            .generics
+            .lifetimes()
+            .map(|l| l.lifetime.ident.to_string())
+            .filter(|l| uses.reference.contains(l) && !uses.other.contains(l))
+            .collect();
+    }
 
-        derive.error_with(
-            &Span::call_site(),
-            "all of the following lifetimes are already used: 'o, 'ow, .. 'ownable",
-            Lifetime::new("'error", Span::call_site()),
-        )
+    pub(crate) fn is_reference_type(&self, ty: &Type) -> bool {
+        matches!(ty, Type::Reference(TypeReference { lifetime: Some(l), .. }) if self.is_kept_lifetime(&l.ident))
     }
 
-    fn contains_lifetime(generics: &Generics, lifetime: &str) -> bool {
+    pub(crate) fn contains_lifetime(generics: &Generics, lifetime: &str) -> bool {
         generics.params.iter().any(|p| match p {
             GenericParam::Lifetime(l) => l.lifetime.ident == lifetime,
             GenericParam::Type(_) | GenericParam::Const(_) => false,
@@ -60,6 +148,62 @@ impl DeriveAttribute {
 #[darling(attributes(ownable))]
 pub(crate) struct FieldAttribute {
     pub(crate) clone: Option<bool>,
+    pub(crate) parallel: Option<Override<usize>>,
+    #[darling(default)]
+    skip: bool,
+    default: Option<Override<Expr>>,
+    pub(crate) bound: Option<BoundAttribute>,
+    with: Option<Path>,
+    to_borrowed_with: Option<Path>,
+    to_owned_with: Option<Path>,
+    into_owned_with: Option<Path>,
+    into_owned_compact_with: Option<Path>,
+    into_leaked_with: Option<Path>,
+    rebase_with: Option<Path>,
+    for_each_borrow_with: Option<Path>,
+    visit_with: Option<Path>,
+    map_cows_with: Option<Path>,
+}
+
+impl FieldAttribute {
+    // Whether the field is not converted (in some modes).
+    pub(crate) fn is_skipped(&self) -> bool {
+        self.skip || self.default.is_some()
+    }
+
+    // The value of the field if it's replaced in the mode (`skip` in all, `default` in all but
+    // `to_borrowed`).
+    pub(crate) fn replacement(&self, mode: Mode) -> Option<TokenStream> {
+        if !(self.skip || (self.default.is_some() && !matches!(mode, Mode::ToBorrowed))) {
+            return None;
+        }
+        Some(match &self.default {
+            Some(Override::Explicit(expr)) => expr.to_token_stream(),
+            Some(Override::Inherit) | None => quote! { ::core::default::Default::default() },
+        })
+    }
+
+    // The custom function for the mode (either set for the mode or from the `with` module).
+    pub(crate) fn with(&self, mode: Mode) -> Option<TokenStream> {
+        let function = match mode {
+            Mode::ToBorrowed => &self.to_borrowed_with,
+            Mode::ToOwned => &self.to_owned_with,
+            Mode::IntoOwned => &self.into_owned_with,
+            Mode::IntoOwnedCompact => &self.into_owned_compact_with,
+            Mode::IntoLeaked => &self.into_leaked_with,
+            Mode::Rebase => &self.rebase_with,
+            Mode::ForEachBorrow => &self.for_each_borrow_with,
+            Mode::OwnableVisit => &self.visit_with,
+            Mode::MapCows => &self.map_cows_with,
+        };
+        if let Some(function) = function {
+            Some(function.to_token_stream())
+        } else {
+            let module = self.with.as_ref()?;
+            let function = mode.function();
+            Some(quote! { #module::#function })
+        }
+    }
 }
 
 pub(crate) trait OrAssign<Rhs> {
@@ -78,6 +222,205 @@ impl OrAssign<&DeriveAttribute> for FieldAttribute {
     }
 }
 
+//
+// BoundAttribute
+//
+
+// Either the bounds for all modes (`bound = ".."`) or per mode (`bound(into_owned = "..")`).
+#[derive(Default, Debug)]
+pub(crate) struct BoundAttribute {
+    all: Option<Vec<WherePredicate>>,
+    modes: Vec<(Mode, Vec<WherePredicate>)>,
+}
+
+impl BoundAttribute {
+    pub(crate) fn get(&self, mode: Mode) -> Option<&[WherePredicate]> {
+        self.modes
+            .iter()
+            .find(|(m, _)| *m == mode)
+            .map(|(_, b)| b.as_slice())
+            .or(self.all.as_deref())
+    }
+
+    fn parse(value: &str) -> Result<Vec<WherePredicate>, Error> {
+        if value.trim().is_empty() {
+            Ok(Vec::new())
+        } else {
+            let where_clause: WhereClause =
+                syn::parse_str(&format!("where {value}")).map_err(Error::custom)?;
+            Ok(where_clause.predicates.into_iter().collect())
+        }
+    }
+}
+
+impl FromMeta for BoundAttribute {
+    fn from_string(value: &str) -> Result<Self, Error> {
+        Ok(Self {
+            all: Some(Self::parse(value)?),
+            modes: Vec::new(),
+        })
+    }
+
+    fn from_list(items: &[NestedMeta]) -> Result<Self, Error> {
+        let mut errors = Error::accumulator();
+        let mut modes = Vec::new();
+        for item in items {
+            errors.handle_in(|| {
+                let nv = match item {
+                    NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                    _ => return Err(Error::unsupported_format("non name-value").with_span(item)),
+                };
+                let name = nv.path.get_ident().map(ToString::to_string);
+                let mode = Mode::ALL
+                    .into_iter()
+                    .find(|m| Some(m.function().to_string()) == name)
+                    .ok_or_else(|| Error::unknown_field_path(&nv.path).with_span(&nv.path))?;
+                let value = String::from_expr(&nv.value)?;
+                modes.push((
+                    mode,
+                    Self::parse(&value).map_err(|e| e.with_span(&nv.value))?,
+                ));
+                Ok(())
+            });
+        }
+        errors.finish_with(Self { all: None, modes })
+    }
+}
+
+//
+// FunctionAttribute
+//
+
+// Whether the inherent functions are generated (`function = false`) and how
+// (`function(vis = "pub(crate)", into_owned = "into_static", to_owned = false, alias = "..")`).
+#[derive(Debug)]
+pub(crate) struct FunctionAttribute {
+    enabled: bool,
+    vis: Option<Visibility>,
+    names: Vec<(String, Option<Ident>)>,
+    pub(crate) alias: Option<Ident>,
+}
+
+impl Default for FunctionAttribute {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            vis: None,
+            names: Vec::new(),
+            alias: None,
+        }
+    }
+}
+
+impl FunctionAttribute {
+    // The name of the inherent function of the mode, if it is generated.
+    pub(crate) fn name(&self, mode: Mode) -> Option<TokenStream> {
+        self.configured_name(mode.function())
+    }
+
+    // The name of the function `view` (with `view_type`), if it is generated.
+    pub(crate) fn view_name(&self) -> Option<TokenStream> {
+        self.configured_name(quote!(view))
+    }
+
+    // The name of an inherent function, if it is generated.
+    pub(crate) fn configured_name(&self, function: TokenStream) -> Option<TokenStream> {
+        let function_name = function.to_string();
+        match self.names.iter().find(|(f, _)| *f == function_name) {
+            Some((_, name)) => name.as_ref().map(ToTokens::to_token_stream),
+            None if self.enabled => Some(function),
+            None => None,
+        }
+    }
+
+    pub(crate) fn vis(&self) -> TokenStream {
+        self.vis
+            .as_ref()
+            .map_or_else(|| quote!(pub), ToTokens::to_token_stream)
+    }
+}
+
+impl FromMeta for FunctionAttribute {
+    fn from_word() -> Result<Self, Error> {
+        Ok(Self::default())
+    }
+
+    fn from_bool(value: bool) -> Result<Self, Error> {
+        Ok(Self {
+            enabled: value,
+            ..Self::default()
+        })
+    }
+
+    fn from_list(items: &[NestedMeta]) -> Result<Self, Error> {
+        let mut errors = Error::accumulator();
+        let mut function = Self::default();
+        for item in items {
+            errors.handle_in(|| {
+                let nv = match item {
+                    NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                    _ => return Err(Error::unsupported_format("non name-value").with_span(item)),
+                };
+                let name = nv.path.get_ident().map(ToString::to_string);
+                match name.as_deref() {
+                    Some("vis") => {
+                        let vis = String::from_expr(&nv.value)?;
+                        function.vis = Some(
+                            syn::parse_str(&vis)
+                                .map_err(|e| Error::custom(e).with_span(&nv.value))?,
+                        );
+                    }
+                    Some("alias") => function.alias = Some(Ident::from_expr(&nv.value)?),
+                    _ => {
+                        // the functions of the modes, `view` and `borrows_from`
+                        let function_name = Mode::ALL
+                            .into_iter()
+                            .map(|m| m.function().to_string())
+                            .chain(["view".to_string(), "borrows_from".to_string()])
+                            .find(|f| Some(f) == name.as_ref())
+                            .ok_or_else(|| {
+                                Error::unknown_field_path(&nv.path).with_span(&nv.path)
+                            })?;
+                        // either enabled or disabled (with a boolean) or renamed (with a string)
+                        let name = if let Ok(enabled) = bool::from_expr(&nv.value) {
+                            enabled.then(|| Ident::new(&function_name, nv.path.span()))
+                        } else {
+                            Some(Ident::from_expr(&nv.value)?)
+                        };
+                        function.names.push((function_name, name));
+                    }
+                }
+                Ok(())
+            });
+        }
+        errors.finish_with(function)
+    }
+}
+
+//
+// InlineAttribute
+//
+
+// The inlining of the generated functions (`inline = "never" | "default" | "always"`).
+#[derive(Clone, Copy, Debug, Default, FromMeta)]
+#[darling(rename_all = "snake_case")]
+pub(crate) enum InlineAttribute {
+    Never,
+    Default,
+    #[default]
+    Always,
+}
+
+impl ToTokens for InlineAttribute {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            InlineAttribute::Never => tokens.extend(quote!(#[inline(never)])),
+            InlineAttribute::Default => {}
+            InlineAttribute::Always => tokens.extend(quote!(#[inline(always)])),
+        }
+    }
+}
+
 //
 // LifetimesAttribute
 //
@@ -87,6 +430,10 @@ pub(crate) struct LifetimesAttribute(pub(crate) Vec<String>);
 
 impl FromMeta for LifetimesAttribute {
     fn from_string(value: &str) -> Result<Self, Error> {
+        if value.trim().is_empty() {
+            // no reference lifetimes (i.e. don't infer them)
+            return Ok(Self(Vec::new()));
+        }
         Ok(Self(
             value
                 .split(',')
@@ -99,14 +446,71 @@ impl FromMeta for LifetimesAttribute {
     }
 }
 
+// The lifetimes which are used by references and the ones used otherwise (e.g. by `Cow`), the
+// type itself (e.g. `Box<Self>`) uses its lifetimes the same way.
+struct LifetimeUses<'a> {
+    ident: &'a Ident,
+    lifetimes: Vec<&'a Lifetime>,
+    reference: Vec<String>,
+    other: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for LifetimeUses<'_> {
+    fn visit_type_reference(&mut self, reference: &'ast TypeReference) {
+        if let Some(l) = &reference.lifetime {
+            self.reference.push(l.ident.to_string());
+        }
+        // the referenced type may use lifetimes otherwise
+        self.visit_type(&reference.elem);
+    }
+
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        if let (None, Some(segment)) = (&type_path.qself, type_path.path.segments.last()) {
+            if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                let lifetimes = arguments.args.iter().filter_map(|a| match a {
+                    GenericArgument::Lifetime(l) => Some(l),
+                    _ => None,
+                });
+                if segment.ident == *self.ident && lifetimes.eq(self.lifetimes.iter().copied()) {
+                    // only the other arguments may use the lifetimes otherwise
+                    for argument in &arguments.args {
+                        if !matches!(argument, GenericArgument::Lifetime(_)) {
+                            self.visit_generic_argument(argument);
+                        }
+                    }
+                    return;
+                }
+            }
+        }
+        syn::visit::visit_type_path(self, type_path);
+    }
+
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.other.push(lifetime.ident.to_string());
+    }
+}
+
+// All lifetimes of a type.
+#[derive(Default)]
+struct Lifetimes<'ast>(Vec<&'ast Lifetime>);
+
+impl<'ast> Visit<'ast> for Lifetimes<'ast> {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.0.push(lifetime);
+    }
+}
+
 impl Derive<'_> {
     pub(crate) fn verify_lifetimes(&mut self) {
-        for lt in &self.attribute.reference_lifetime.0 {
-            if !DeriveAttribute::contains_lifetime(self.generics, lt) {
-                self.error(
-                    &self.attribute.reference_lifetime.span(),
-                    format!("lifetime \"'{lt}\" is not used"),
-                );
+        let attribute = self.attribute;
+        for lifetimes in [&attribute.reference_lifetime, &attribute.keep_lifetime]
+            .into_iter()
+            .flatten()
+        {
+            for lt in &lifetimes.0 {
+                if !DeriveAttribute::contains_lifetime(self.generics, lt) {
+                    self.error(&lifetimes.span(), format!("lifetime \"'{lt}\" is not used"));
+                }
             }
         }
     }
@@ -0,0 +1,170 @@
+use crate::attribute::{FieldAttribute, OrAssign};
+use crate::derive::Derive;
+use crate::mode::Mode;
+use darling::FromAttributes;
+use proc_macro2::Ident;
+use quote::ToTokens;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{parse_quote, Attribute, Data, Field, Lifetime, Type, TypePath, WherePredicate};
+
+impl Derive<'_> {
+    // The bounds of the mode, either set with `bound` or inferred from the fields. The converted
+    // types are bound with the lifetimes replaced by `source` (or as they are) and by `target`.
+    pub(crate) fn generate_bounds(
+        &self,
+        mode: Mode,
+        source: Option<&Lifetime>,
+        target: &Lifetime,
+    ) -> Vec<WherePredicate> {
+        let (types, mut bounds) =
+            if let Some(bounds) = (self.attribute.bound.as_ref()).and_then(|b| b.get(mode)) {
+                (Vec::new(), bounds.to_vec())
+            } else {
+                self.infer_bounds(mode)
+            };
+        // the bounds of the modes with the same type use the lifetimes of the type
+        if !(mode.read_only() || mode.same_type()) {
+            for bound in &mut bounds {
+                self.set_lifetimes(target).visit_where_predicate_mut(bound);
+            }
+        }
+        for ty in types {
+            let mut ty_source = ty.clone();
+            if let Some(source) = source {
+                self.set_lifetimes(source).visit_type_mut(&mut ty_source);
+            }
+            let mut ty_target = ty;
+            self.set_lifetimes(target).visit_type_mut(&mut ty_target);
+            bounds.push(
+                syn::parse2(mode.bound(&self.krate, &ty_source, &ty_target, target)).unwrap(),
+            );
+        }
+        bounds
+    }
+
+    // The types which are converted by the mode (without `bound` on the container).
+    pub(crate) fn bound_types(&self, mode: Mode) -> Vec<Type> {
+        if (self.attribute.bound.as_ref()).map_or(false, |b| b.get(mode).is_some()) {
+            Vec::new()
+        } else {
+            self.infer_bounds(mode).0
+        }
+    }
+
+    // The type parameters and projections (e.g. `<T as Trait<'a>>::Assoc`) used by converted
+    // fields and the bounds of the fields (with `bound`).
+    fn infer_bounds(&self, mode: Mode) -> (Vec<Type>, Vec<WherePredicate>) {
+        let mut uses = Uses {
+            types: self.generics.type_params().map(|t| &t.ident).collect(),
+            found: Vec::new(),
+        };
+        let mut bounds = Vec::new();
+        // errors of the attributes are reported by the conversion
+        let attributes =
+            |attrs: &[Attribute]| FieldAttribute::from_attributes(attrs).unwrap_or_default();
+        let mut infer = |variant_attribute: &FieldAttribute, field: &Field| {
+            let mut field_attribute = attributes(&field.attrs);
+            field_attribute.or_assign(variant_attribute);
+            field_attribute.or_assign(self.attribute);
+            if let Some(field_bounds) = (field_attribute.bound.as_ref()).and_then(|b| b.get(mode)) {
+                bounds.extend(field_bounds.iter().cloned());
+            } else if !(self.attribute.is_reference_type(&field.ty)
+                || field_attribute.clone.unwrap_or(false)
+                || field_attribute.is_skipped()
+                || field_attribute.with(mode).is_some()
+                || (!mode.read_only()
+                    && !mode.same_type()
+                    && self.attribute.is_kept_type(&field.ty)))
+            {
+                uses.visit_type(&field.ty);
+            }
+        };
+        match self.data {
+            Data::Struct(data) => {
+                for field in &data.fields {
+                    infer(&FieldAttribute::default(), field);
+                }
+            }
+            Data::Enum(data) => {
+                for variant in &data.variants {
+                    let variant_attribute = attributes(&variant.attrs);
+                    for field in &variant.fields {
+                        infer(&variant_attribute, field);
+                    }
+                }
+            }
+            // the union is cloned as a whole, thus the fields have to be `Copy` (or `clone`)
+            Data::Union(data) => {
+                for field in &data.fields.named {
+                    let field_attribute = attributes(&field.attrs);
+                    if let Some(field_bounds) =
+                        (field_attribute.bound.as_ref()).and_then(|b| b.get(mode))
+                    {
+                        bounds.extend(field_bounds.iter().cloned());
+                    } else if !(mode.read_only()
+                        || mode.as_ref().is_empty()
+                        || field_attribute.clone.unwrap_or(false))
+                    {
+                        let ty = &field.ty;
+                        bounds.push(parse_quote!(#ty: ::core::marker::Copy));
+                    }
+                }
+            }
+        }
+        (uses.found, bounds)
+    }
+}
+
+// The containers (of `ownable-core`) which convert their type parameters.
+const CONTAINERS: &[&str] = &[
+    "Option",
+    "Box",
+    "Vec",
+    "VecDeque",
+    "LinkedList",
+    "BinaryHeap",
+    "BTreeSet",
+    "BTreeMap",
+    "HashSet",
+    "HashMap",
+];
+
+// Search for the type parameters and projections, which are used directly or within containers,
+// tuples and arrays (but not in `Cow` payloads, other types or references).
+struct Uses<'a> {
+    types: Vec<&'a Ident>,
+    found: Vec<Type>,
+}
+
+impl Uses<'_> {
+    fn found(&mut self, ty: &Type) {
+        let tokens = ty.to_token_stream().to_string();
+        if !(self.found.iter()).any(|t| t.to_token_stream().to_string() == tokens) {
+            self.found.push(ty.clone());
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for Uses<'_> {
+    fn visit_type(&mut self, ty: &'ast Type) {
+        match ty {
+            Type::Path(TypePath { qself: Some(_), .. }) => self.found(ty),
+            Type::Path(TypePath { qself: None, path }) => {
+                let first = path.segments.first().map(|s| &s.ident);
+                if first.map_or(false, |f| self.types.contains(&f)) {
+                    // the type parameter `T` or a projection `T::Assoc`
+                    self.found(ty);
+                } else if let Some(segment) = path.segments.last() {
+                    if CONTAINERS.iter().any(|c| segment.ident == c) {
+                        syn::visit::visit_path_arguments(self, &segment.arguments);
+                    }
+                }
+            }
+            Type::Array(array) => self.visit_type(&array.elem),
+            Type::Slice(slice) => self.visit_type(&slice.elem),
+            Type::Group(_) | Type::Paren(_) | Type::Tuple(_) => syn::visit::visit_type(self, ty),
+            _ => {}
+        }
+    }
+}
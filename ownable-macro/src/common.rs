@@ -1,5 +1,7 @@
 use crate::attribute::{FieldAttribute, OrAssign};
 use crate::derive::Derive;
+use crate::mode::Mode;
+use darling::util::Override;
 use darling::FromAttributes;
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::{quote, ToTokens};
@@ -20,17 +22,30 @@ impl Derive<'_> {
             }
             field_attribute.or_assign(self.attribute);
             let name = d.ident.as_ref().unwrap();
+            let field = name.to_string();
             let call = self.create_call(
                 &field_attribute,
                 &name.into_token_stream(),
+                (
+                    variant.as_ref().map(|v| &v.ident),
+                    field.trim_start_matches("r#"),
+                ),
                 variant.is_none(),
                 &d.ty,
             );
-            fields.push(quote! {#name: #call});
+            if self.mode.read_only() {
+                if !call.is_empty() {
+                    fields.push(quote! {#call;});
+                }
+            } else {
+                fields.push(quote! {#name: #call});
+            }
         }
 
-        let name = self.ident;
-        if let Some(variant) = variant {
+        let name = self.target();
+        if self.mode.read_only() {
+            quote! {{ #(#fields)* }}
+        } else if let Some(variant) = variant {
             let variant_name = &variant.ident;
             quote! {#name :: #variant_name { #(#fields),* }}
         } else {
@@ -53,6 +68,7 @@ impl Derive<'_> {
                 fields.push(self.create_call(
                     &field_attribute,
                     &Ident::new(&format!("arg{i}"), Span::call_site()).to_token_stream(),
+                    (Some(&variant.ident), &i.to_string()),
                     false,
                     &d.ty,
                 ));
@@ -61,14 +77,18 @@ impl Derive<'_> {
                 fields.push(self.create_call(
                     &field_attribute,
                     &LitInt::new(&i.to_string(), Span::call_site()).to_token_stream(),
+                    (None, &i.to_string()),
                     true,
                     &d.ty,
                 ));
             }
         }
 
-        let name = self.ident;
-        if let Some(variant) = variant {
+        let name = self.target();
+        if self.mode.read_only() {
+            let fields = fields.iter().filter(|call| !call.is_empty());
+            quote! {{ #(#fields;)* }}
+        } else if let Some(variant) = variant {
             let variant_name = &variant.ident;
             quote! {#name :: #variant_name ( #(#fields),* )}
         } else {
@@ -80,38 +100,140 @@ impl Derive<'_> {
         &mut self,
         field_attribute: &FieldAttribute,
         index: &TokenStream,
+        (variant, field): (Option<&Ident>, &str),
         with_self: bool,
         ty: &Type,
     ) -> TokenStream {
-        if let Type::Reference(TypeReference {
-            lifetime: Some(l), ..
+        if self.mode.read_only() && field_attribute.is_skipped() {
+            Self::create_ignore(index, with_self)
+        } else if self.mode.same_type() && field_attribute.is_skipped() {
+            Self::create_move(index, with_self)
+        } else if let Some(replacement) = field_attribute.replacement(self.mode) {
+            if with_self {
+                replacement
+            } else {
+                quote! {{ let _ = #index; #replacement }}
+            }
+        } else if let Type::Reference(TypeReference {
+            lifetime: Some(l),
+            mutability,
+            ..
         }) = ty
         {
-            if self.attribute.is_reference_lifetime(&l.ident) {
-                if with_self {
-                    quote! { self . #index}
+            if self.attribute.is_kept_lifetime(&l.ident) {
+                if self.mode.read_only() {
+                    Self::create_ignore(index, with_self)
+                } else if mutability.is_some() && !self.mode.as_ref().is_empty() {
+                    self.error(
+                        ty,
+                        format!(
+                            "a mutable reference can't be copied by `{}`, it can only be moved by the consuming functions (e.g. `into_owned`)",
+                            self.mode.function()
+                        ),
+                    );
+                    quote! { todo!() }
                 } else {
-                    quote! { #index }
+                    Self::create_move(index, with_self)
                 }
             } else {
                 self.error(ty,"References are not supported out of the box, see: https://docs.rs/ownable/*/ownable/#references");
                 quote! { todo!() }
             }
-        } else if field_attribute.clone.unwrap_or(false) {
+        } else if let Some(function) = field_attribute.with(self.mode) {
+            let arguments = self.mode.field_arguments(&self.krate, variant, field);
             if with_self {
-                quote! {::core::clone::Clone::clone(& self . #index)}
+                let as_ref = self.mode.as_ref();
+                let value = self.create_value(index);
+                quote! {#function(#as_ref #value #arguments)}
+            } else {
+                quote! {#function(#index #arguments)}
+            }
+        } else if field_attribute.clone.unwrap_or(false) || field_attribute.is_skipped() {
+            // fields with `default` are cloned by `to_borrowed`
+            if self.mode.read_only() {
+                Self::create_ignore(index, with_self)
+            } else if self.mode.same_type() {
+                Self::create_move(index, with_self)
+            } else if with_self {
+                let value = self.create_value(index);
+                quote! {::core::clone::Clone::clone(& #value)}
             } else {
                 quote! {::core::clone::Clone::clone(& #index)}
             }
+        } else if !self.mode.read_only()
+            && !self.mode.same_type()
+            && self.attribute.is_kept_type(ty)
+        {
+            // the lifetime is kept, thus the value is moved (or cloned if borrowed)
+            if self.mode.as_ref().is_empty() {
+                Self::create_move(index, with_self)
+            } else if with_self {
+                let value = self.create_value(index);
+                quote! {::core::clone::Clone::clone(& #value)}
+            } else {
+                quote! {::core::clone::Clone::clone(& #index)}
+            }
+        } else if self.packed && with_self && self.mode == Mode::ToBorrowed {
+            // the copy of a packed field can't be borrowed, thus the copy is the result
+            self.create_value(index)
         } else {
-            let trait_name = self.mode.name();
+            let trait_name = self.mode.name(&self.krate);
             let trait_function = self.mode.function();
-            if with_self {
-                let as_ref = self.mode.as_ref();
-                quote! {#trait_name::#trait_function(#as_ref self . #index)}
+            let arguments = self.mode.field_arguments(&self.krate, variant, field);
+            let (as_ref, value) = if with_self {
+                (self.mode.as_ref(), self.create_value(index))
             } else {
-                quote! {#trait_name::#trait_function(#index)}
+                (quote!(), quote! {#index})
+            };
+            let call = match (&field_attribute.parallel, self.mode.parallel(&self.krate)) {
+                (Some(Override::Inherit), Some((par_name, par_function))) => {
+                    quote! {#par_name::#par_function(#as_ref #value)}
+                }
+                (Some(Override::Explicit(threshold)), Some((par_name, par_function))) => {
+                    quote! {
+                        if #value.len() >= #threshold {
+                            #par_name::#par_function(#as_ref #value)
+                        } else {
+                            #trait_name::#trait_function(#as_ref #value)
+                        }
+                    }
+                }
+                _ => quote! {#trait_name::#trait_function(#as_ref #value #arguments)},
+            };
+            if self.owned_type().is_some() && Self::cow_payload(ty).is_some() {
+                // the owned `Cow` is unwrapped (with `Cow::into_owned`)
+                quote! {(#call).into_owned()}
+            } else {
+                call
             }
         }
     }
+
+    // A field of `self`, which is copied (or moved) out if the struct is packed (since references
+    // to packed fields may be unaligned).
+    fn create_value(&self, index: &TokenStream) -> TokenStream {
+        if self.packed {
+            quote! { { self . #index } }
+        } else {
+            quote! { self . #index }
+        }
+    }
+
+    // A field which is moved as it is.
+    fn create_move(index: &TokenStream, with_self: bool) -> TokenStream {
+        if with_self {
+            quote! { self . #index }
+        } else {
+            quote! { #index }
+        }
+    }
+
+    // A field which is not visited in a read only mode, bindings have to be used anyway.
+    fn create_ignore(index: &TokenStream, with_self: bool) -> TokenStream {
+        if with_self {
+            TokenStream::new()
+        } else {
+            quote! { let _ = #index }
+        }
+    }
 }
@@ -2,18 +2,23 @@ use crate::attribute::DeriveAttribute;
 use crate::mode::Mode;
 use darling::error::Accumulator;
 use darling::{Error, FromDeriveInput};
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream};
 use std::fmt::Display;
 use syn::spanned::Spanned;
-use syn::{Data, DeriveInput, Generics};
+use syn::token::Paren;
+use syn::{parenthesized, Attribute, Data, DeriveInput, Generics, Lifetime, Path, Visibility};
 
 // a struct to just hold all global data together (rather than passing them always around)
 pub(crate) struct Derive<'a> {
     errors: Accumulator,
     pub(crate) ident: &'a Ident,
+    pub(crate) vis: &'a Visibility,
     pub(crate) generics: &'a Generics,
+    pub(crate) data: &'a Data,
     pub(crate) attribute: &'a DeriveAttribute,
+    pub(crate) krate: Path,
     pub(crate) mode: Mode,
+    pub(crate) packed: bool,
 }
 
 impl Derive<'_> {
@@ -30,31 +35,126 @@ impl Derive<'_> {
         self.errors.push(Error::custom(t).with_span(s));
         e
     }
+
+    // A lifetime, which isn't used by the generics of the type.
+    pub(crate) fn new_lifetime(&mut self) -> Lifetime {
+        const TRY_LIFETIME: &str = "ownable";
+
+        for l in TRY_LIFETIME
+            .char_indices()
+            .map(move |(pos, _)| &TRY_LIFETIME[..=pos])
+        {
+            if !DeriveAttribute::contains_lifetime(self.generics, l) {
+                return Lifetime::new(&format!("'{l}"), Span::call_site());
+            }
+        }
+
+        self.error_with(
+            &Span::call_site(),
+            "all of the following lifetimes are already used: 'o, 'ow, .. 'ownable",
+            Lifetime::new("'error", Span::call_site()),
+        )
+    }
 }
 
 pub(crate) fn derive(input: &DeriveInput, mode: Mode) -> TokenStream {
+    derive_modes(input, false, |attribute, errors| {
+        // the flags select the derives of `Ownable`, they would be ignored by a single derive
+        for derived in Mode::OWNABLE {
+            if let Some(value) = attribute.derived_flag(derived) {
+                errors.push(
+                    Error::custom(format!(
+                        "`{}` is only supported by the derive `Ownable`",
+                        derived.function()
+                    ))
+                    .with_span(&value.span()),
+                );
+            }
+        }
+        vec![mode]
+    })
+}
+
+// The derive `Ownable`: `IntoOwned`, `ToBorrowed` and `ToOwned` (except the disabled ones).
+pub(crate) fn derive_ownable(input: &DeriveInput) -> TokenStream {
+    derive_modes(input, true, |attribute, _| {
+        (Mode::OWNABLE.into_iter())
+            .filter(|m| attribute.is_derived(*m))
+            .collect()
+    })
+}
+
+// The attributes are parsed and verified once for all modes.
+fn derive_modes(
+    input: &DeriveInput,
+    ownable: bool,
+    modes: impl FnOnce(&DeriveAttribute, &mut Accumulator) -> Vec<Mode>,
+) -> TokenStream {
     let mut errors = Error::accumulator();
     let attribute = &mut errors
         .handle_in(|| DeriveAttribute::from_derive_input(input))
         .unwrap_or_default();
+    attribute.infer_reference_lifetimes(input);
+    let modes = modes(attribute, &mut errors);
     let mut derive = Derive {
         errors,
         ident: &input.ident,
+        vis: &input.vis,
         generics: &input.generics,
+        data: &input.data,
+        krate: attribute.krate(),
         attribute,
-        mode,
+        mode: modes.first().copied().unwrap_or(Mode::IntoOwned),
+        packed: is_packed(&input.attrs),
     };
     derive.verify_lifetimes();
 
-    let mut result = match &input.data {
-        Data::Struct(data) => derive.derive_struct(data),
-        Data::Enum(data) => derive.derive_enum(data),
-        Data::Union(_data) => {
-            derive.error_with(input, "union is not supported", TokenStream::new())
+    let mut result = TokenStream::new();
+    for mode in modes {
+        derive.mode = mode;
+        result.extend(match &input.data {
+            Data::Struct(data) => derive.derive_struct(data),
+            Data::Enum(data) => derive.derive_enum(data),
+            Data::Union(data) => derive.derive_union(data),
+        });
+        // the companion types are defined once, by the derives `IntoOwned` and `ToBorrowed`
+        if !ownable && mode == Mode::IntoOwned {
+            result.extend(derive.generate_owned_type(input));
+        }
+        if !ownable && mode == Mode::ToBorrowed {
+            result.extend(derive.generate_view_type(input));
+        }
+    }
+    // or by `Ownable` (also if all its derives are disabled)
+    if ownable {
+        result.extend(derive.generate_owned_type(input));
+        result.extend(derive.generate_view_type(input));
+    }
+    // the same error (e.g. of a field attribute) is found by all modes, but reported once
+    let mut reported = Vec::new();
+    for error in derive.errors.into_inner().into_iter().flatten() {
+        let key = (error.to_string(), format!("{:?}", error.span()));
+        if !reported.contains(&key) {
+            reported.push(key);
+            result.extend(error.write_errors());
         }
-    };
-    if let Err(errors) = derive.errors.finish() {
-        result.extend(errors.write_errors());
     }
     result
 }
+
+// Whether the type has `#[repr(packed)]` (or `packed(N)`), then the fields can't be referenced.
+fn is_packed(attrs: &[Attribute]) -> bool {
+    attrs.iter().filter(|a| a.path().is_ident("repr")).any(|a| {
+        let mut packed = false;
+        // errors of `repr` are reported by the compiler
+        let _ = a.parse_nested_meta(|meta| {
+            packed |= meta.path.is_ident("packed");
+            if meta.input.peek(Paren) {
+                let _content;
+                parenthesized!(_content in meta.input);
+            }
+            Ok(())
+        });
+        packed
+    })
+}
@@ -45,6 +45,11 @@ impl Derive<'_> {
     fn match_unit(&self, variant: &Variant) -> TokenStream {
         let name = self.ident;
         let variant_name = &variant.ident;
-        quote! {#name :: #variant_name => #name :: #variant_name}
+        if self.mode.read_only() {
+            quote! {#name :: #variant_name => {}}
+        } else {
+            let target = self.target();
+            quote! {#name :: #variant_name => #target :: #variant_name}
+        }
     }
 }
@@ -1,50 +1,70 @@
 use crate::derive::Derive;
 use crate::mode::Mode;
 use proc_macro2::{Span, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use std::iter::once;
+use syn::punctuated::Punctuated;
 use syn::token::{Colon, Where};
+use syn::visit_mut::VisitMut;
 use syn::{
-    GenericParam, Generics, Lifetime, LifetimeParam, Path, PathSegment, PredicateType, Type,
-    TypeParam, TypeParamBound, TypePath, WhereClause, WherePredicate,
+    GenericParam, Generics, Lifetime, LifetimeParam, Path, PathSegment, PredicateLifetime,
+    PredicateType, TraitBoundModifier, Type, TypeParam, TypeParamBound, TypePath, WhereClause,
+    WherePredicate,
 };
 
 impl Derive<'_> {
     pub(crate) fn generate(&mut self, inner: &TokenStream) -> TokenStream {
+        let inner = &self.generate_guard(inner);
         match self.mode {
             Mode::ToBorrowed => self.generate_mode_to_borrowed(inner),
-            Mode::ToOwned | Mode::IntoOwned => self.generate_mode_in_to_owned(inner),
+            Mode::ToOwned
+            | Mode::IntoOwned
+            | Mode::IntoOwnedCompact
+            | Mode::IntoLeaked
+            | Mode::Rebase => self.generate_mode_in_to_owned(inner),
+            Mode::ForEachBorrow | Mode::OwnableVisit | Mode::MapCows => {
+                self.generate_mode_same_type(inner)
+            }
         }
     }
 
-    fn generate_mode_to_borrowed(&mut self, inner: &TokenStream) -> TokenStream {
-        if self.generics.lifetimes().count() == 0 {
-            self.error(
-                self.ident,
-                "ToBorrowed can be only derived for a struct/enum with a lifetime",
-            );
+    fn generate_guard(&self, inner: &TokenStream) -> TokenStream {
+        if self.attribute.stack_safe.unwrap_or(false) {
+            let krate = &self.krate;
+            quote! {
+                #krate::stack::guard(move || #inner)
+            }
+        } else {
+            inner.clone()
         }
+    }
 
-        let lifetime_our = &self.attribute.new_lifetime(self);
-        let lifetime_placeholder = &Lifetime::new("'_", Span::call_site());
+    fn generate_mode_to_borrowed(&mut self, inner: &TokenStream) -> TokenStream {
+        let lifetime_our = &self.new_lifetime();
         let mut generics_definition = self.generate_generics(None);
         generics_definition
             .params
             .insert(0, LifetimeParam::new(lifetime_our.clone()).into());
         let generics_our = self.generate_generics(Some(lifetime_our));
-        let generics_placeholder = self.generate_generics(Some(lifetime_placeholder));
-        let generics_where = self.generate_where(lifetime_our);
+        let mut generics_where = self.generate_where(Some(lifetime_our));
+        generics_where.predicates.extend(self.generate_bounds(
+            self.mode,
+            Some(lifetime_our),
+            lifetime_our,
+        ));
 
         let name = self.ident;
-        let trait_name = Mode::ToBorrowed.name();
+        let trait_name = Mode::ToBorrowed.name(&self.krate);
         let doc = Mode::ToBorrowed.doc();
-        let function = if self.attribute.function.unwrap_or(true) {
+        let function = if let Some(function) = self.attribute.function.name(self.mode) {
+            let vis = self.attribute.function.vis();
+            let inline = self.attribute.inline;
             quote! {
                 impl #generics_definition #name #generics_our #generics_where
                 {
                     #[doc=#doc]
-                    #[inline(always)]
-                    pub fn to_borrowed(&self) -> #name #generics_placeholder {
+                    #inline
+                    #vis fn #function(&#lifetime_our self) -> #name #generics_our {
                         #trait_name::to_borrowed(self)
                     }
                 }
@@ -66,26 +86,85 @@ impl Derive<'_> {
     }
 
     fn generate_mode_in_to_owned(&mut self, inner: &TokenStream) -> TokenStream {
-        let lifetime_placeholder = &Lifetime::new("'_", Span::call_site());
-        let lifetime_static = &Lifetime::new("'static", Span::call_site());
-        let generics_definition = self.generate_generics(None);
-        let generics_placeholder = self.generate_generics(Some(lifetime_placeholder));
-        let generics_static = self.generate_generics(Some(lifetime_static));
-        let generics_where = self.generate_where(lifetime_static);
+        let mut generics_definition = self.generate_generics(None);
+        // the lifetimes of the source are named (e.g. for their where-clauses)
+        for (i, l) in self.generics.lifetimes().enumerate() {
+            if !self.attribute.is_kept_lifetime(&l.lifetime.ident) {
+                generics_definition
+                    .params
+                    .insert(i, LifetimeParam::new(l.lifetime.clone()).into());
+            }
+        }
+        let (lifetime_target, trait_lifetime) = if self.mode.with_lifetime() {
+            let lifetime_our = self.new_lifetime();
+            generics_definition
+                .params
+                .insert(0, LifetimeParam::new(lifetime_our.clone()).into());
+            let trait_lifetime = quote! {<#lifetime_our>};
+            (lifetime_our, trait_lifetime)
+        } else {
+            (
+                Lifetime::new("'static", Span::call_site()),
+                TokenStream::new(),
+            )
+        };
+        let (_, generics_self, _) = self.generics.split_for_impl();
+        let generics_target = self.generate_generics(Some(&lifetime_target));
+        let mut generics_where = self.generate_where(None);
+        let where_target = self.generate_where(Some(&lifetime_target));
+        for mut wp in where_target.predicates {
+            // the relaxed bounds (e.g. `?Sized`) are only allowed once
+            if let WherePredicate::Type(pt) = &mut wp {
+                pt.bounds = (pt.bounds.iter())
+                    .filter(|b| !matches!(b, TypeParamBound::Trait(t) if !matches!(t.modifier, TraitBoundModifier::None)))
+                    .cloned()
+                    .collect();
+                if pt.bounds.is_empty() {
+                    continue;
+                }
+            }
+            let tokens = wp.to_token_stream().to_string();
+            if !(generics_where.predicates.iter())
+                .any(|p| p.to_token_stream().to_string() == tokens)
+            {
+                generics_where.predicates.push(wp);
+            }
+        }
+        generics_where
+            .predicates
+            .extend(self.generate_bounds(self.mode, None, &lifetime_target));
 
         let name = self.ident;
-        let trait_name = self.mode.name();
+        let trait_name = self.mode.name(&self.krate);
         let trait_function = self.mode.function();
         let as_ref = self.mode.as_ref();
+        let output = self.mode.output();
+        let parameters = self.mode.parameters(&self.krate, &lifetime_target);
+        let arguments = self.mode.arguments();
         let doc = self.mode.doc();
-        let function = if self.attribute.function.unwrap_or(true) {
+        let target = if let Some(owned_type) = self.owned_type() {
+            let generics = self.generate_generics(None);
+            let (_, generics, _) = generics.split_for_impl();
+            quote! { #owned_type #generics }
+        } else {
+            quote! { #name #generics_target }
+        };
+        let owned = if let Mode::IntoOwnedCompact = self.mode {
+            // the type is defined by the super trait `IntoOwned`
+            TokenStream::default()
+        } else {
+            quote! { type #output = #target; }
+        };
+        let function = if let Some(function) = self.attribute.function.name(self.mode) {
+            let vis = self.attribute.function.vis();
+            let inline = self.attribute.inline;
             quote! {
-                impl #generics_definition #name #generics_placeholder #generics_where
+                impl #generics_definition #name #generics_self #generics_where
                 {
                     #[doc=#doc]
-                    #[inline(always)]
-                    pub fn #trait_function(#as_ref self) -> #name #generics_static {
-                        #trait_name::#trait_function(self)
+                    #inline
+                    #vis fn #function(#as_ref self #parameters) -> #target {
+                        #trait_name::#trait_function(self #arguments)
                     }
                 }
             }
@@ -93,27 +172,120 @@ impl Derive<'_> {
             TokenStream::default()
         };
 
+        let alias = self.generate_alias(&target);
+
         quote! {
-            impl #generics_definition #trait_name for #name #generics_placeholder #generics_where
+            impl #generics_definition #trait_name #trait_lifetime for #name #generics_self #generics_where
             {
-                type Owned = #name #generics_static;
-                fn #trait_function(#as_ref self) -> Self::Owned {
+                #owned
+                fn #trait_function(#as_ref self #parameters) -> Self::#output {
                     #inner
                 }
             }
 
             #function
+            #alias
+        }
+    }
+
+    // The alias for the owned type (with `function(alias = "..")`), defined by `IntoOwned`.
+    fn generate_alias(&self, target: &TokenStream) -> TokenStream {
+        match (&self.attribute.function.alias, self.mode) {
+            (Some(alias), Mode::IntoOwned) => {
+                let vis = self.vis;
+                let generics = self.generate_generics(None);
+                let doc = format!(
+                    " [`{}`] after obtaining ownership (with `into_owned`).",
+                    self.ident
+                );
+                quote! {
+                    #[doc=#doc]
+                    #vis type #alias #generics = #target;
+                }
+            }
+            _ => TokenStream::default(),
+        }
+    }
+
+    // The result is either nothing (read only) or the same type.
+    fn generate_mode_same_type(&mut self, inner: &TokenStream) -> TokenStream {
+        let (generics_definition, generics_our, _) = self.generics.split_for_impl();
+        let mut generics_where = self.generics.where_clause.clone().unwrap_or(WhereClause {
+            where_token: Where::default(),
+            predicates: Punctuated::new(),
+        });
+        generics_where.predicates.extend(self.generate_bounds(
+            self.mode,
+            None,
+            &Lifetime::new("'_", Span::call_site()),
+        ));
+
+        let name = self.ident;
+        let trait_name = self.mode.name(&self.krate);
+        let trait_function = self.mode.function();
+        let parameters = self
+            .mode
+            .parameters(&self.krate, &Lifetime::new("'_", Span::call_site()));
+        let arguments = self.mode.arguments();
+        let as_ref = self.mode.as_ref();
+        let output = if self.mode.read_only() {
+            TokenStream::new()
+        } else {
+            quote!(-> Self)
+        };
+        let doc = self.mode.doc();
+        let inline = self.attribute.inline;
+        let function = if let Some(function) = self.attribute.function.name(self.mode) {
+            let vis = self.attribute.function.vis();
+            quote! {
+                #[doc=#doc]
+                #inline
+                #vis fn #function(#as_ref self #parameters) #output {
+                    #trait_name::#trait_function(self #arguments)
+                }
+            }
+        } else {
+            TokenStream::default()
+        };
+        let provided = self
+            .mode
+            .provided_functions(&self.krate, &self.attribute.function, inline);
+        let functions = if function.is_empty() && provided.is_empty() {
+            TokenStream::default()
+        } else {
+            quote! {
+                impl #generics_definition #name #generics_our #generics_where
+                {
+                    #function
+
+                    #provided
+                }
+            }
+        };
+
+        quote! {
+            impl #generics_definition #trait_name for #name #generics_our #generics_where
+            {
+                fn #trait_function(#as_ref self #parameters) #output {
+                    #inner
+                }
+            }
+
+            #functions
         }
     }
 
-    fn generate_generics(&self, lt: Option<&Lifetime>) -> Generics {
+    // The generics with the kept lifetimes and the other lifetimes replaced by `lt` (or removed),
+    // the bounds and defaults are removed.
+    pub(crate) fn generate_generics(&self, lt: Option<&Lifetime>) -> Generics {
         let mut gen = Generics::default();
 
         for gp in &self.generics.params {
             match gp {
                 GenericParam::Lifetime(l) => {
-                    if self.attribute.is_reference_lifetime(&l.lifetime.ident) {
-                        gen.params.push(l.clone().into());
+                    if self.attribute.is_kept_lifetime(&l.lifetime.ident) {
+                        gen.params
+                            .push(LifetimeParam::new(l.lifetime.clone()).into());
                     } else if let Some(lt) = lt {
                         gen.params.push(LifetimeParam::new(lt.clone()).into());
                     }
@@ -124,7 +296,10 @@ impl Derive<'_> {
                 GenericParam::Const(c) => {
                     if lt.is_none() {
                         // This happens exactly for the definitions of the generics (e.g. `N`)
-                        gen.params.push(c.clone().into());
+                        let mut c = c.clone();
+                        c.eq_token = None;
+                        c.default = None;
+                        gen.params.push(c.into());
                     } else {
                         // This happens for all uses of the generics (e.g. `const N: usize`)
                         gen.params.push(TypeParam::from(c.ident.clone()).into());
@@ -136,11 +311,22 @@ impl Derive<'_> {
         gen
     }
 
-    fn generate_where(&mut self, lt: &Lifetime) -> WhereClause {
+    // The bounds of the generics and the where-clause, the lifetimes are replaced by `lt` (except
+    // the kept lifetimes) or kept as they are.
+    pub(crate) fn generate_where(&mut self, lt: Option<&Lifetime>) -> WhereClause {
         let mut w = Vec::new();
 
         for gp in &self.generics.params {
             match gp {
+                GenericParam::Lifetime(l) => {
+                    if !l.bounds.is_empty() {
+                        w.push(WherePredicate::Lifetime(PredicateLifetime {
+                            lifetime: l.lifetime.clone(),
+                            colon_token: Colon::default(),
+                            bounds: l.bounds.clone(),
+                        }));
+                    }
+                }
                 GenericParam::Type(t) => {
                     w.push(WherePredicate::Type(PredicateType {
                         lifetimes: None,
@@ -155,7 +341,7 @@ impl Derive<'_> {
                         bounds: t.bounds.iter().cloned().collect(),
                     }));
                 }
-                GenericParam::Lifetime(_) | GenericParam::Const(_) => {}
+                GenericParam::Const(_) => {}
             }
         }
 
@@ -172,44 +358,21 @@ impl Derive<'_> {
         }
     }
 
-    fn set_lifetime(&mut self, lt: &Lifetime, wp: WherePredicate) -> Option<WherePredicate> {
+    fn set_lifetime(
+        &mut self,
+        lt: Option<&Lifetime>,
+        mut wp: WherePredicate,
+    ) -> Option<WherePredicate> {
         match wp {
-            WherePredicate::Lifetime(mut l) => {
-                if self.attribute.is_reference_lifetime(&l.lifetime.ident) {
-                    l.bounds = l
-                        .bounds
-                        .into_iter()
-                        .map(|l| self.reference_lifetime_or_clone(l, lt))
-                        .collect();
-                    Some(WherePredicate::Lifetime(l))
-                } else {
-                    None
+            WherePredicate::Lifetime(_) | WherePredicate::Type(_) => {
+                // the higher-ranked lifetimes (e.g. `for<'x>`) are not replaced
+                if let Some(lt) = lt {
+                    self.set_lifetimes(lt).visit_where_predicate_mut(&mut wp);
                 }
-            }
-            WherePredicate::Type(mut pt) => {
-                pt.lifetimes = None;
-                pt.bounds = pt
-                    .bounds
-                    .into_iter()
-                    .map(|tpb| match tpb {
-                        TypeParamBound::Lifetime(l) => {
-                            TypeParamBound::Lifetime(self.reference_lifetime_or_clone(l, lt))
-                        }
-                        t => t,
-                    })
-                    .collect();
-                Some(WherePredicate::Type(pt))
+                Some(wp)
             }
             // When stable, enable: #[cfg_attr(test, deny(non_exhaustive_omitted_patterns))]
             _ => self.error_with(&wp, "Unsupported WherePredicate", None),
         }
     }
-
-    fn reference_lifetime_or_clone(&self, l: Lifetime, lt: &Lifetime) -> Lifetime {
-        if self.attribute.is_reference_lifetime(&l.ident) {
-            l
-        } else {
-            lt.clone()
-        }
-    }
 }
@@ -5,20 +5,31 @@
 //! This crate is not to be used on it's own, please see <https://docs.rs/ownable>.
 
 mod attribute;
+mod bound;
 mod common;
 mod derive;
 mod r#enum;
 mod generate;
 mod mode;
+mod owned_type;
 mod r#struct;
+mod r#union;
+mod view_type;
 
-use crate::derive::derive;
+use crate::derive::{derive, derive_ownable};
 use crate::mode::Mode;
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
 // This mod uses proc_macro::TokenStream while all others use proc_macro2::TokenStream!
 
+/// Derive `into_owned`, `to_borrowed` and `to_owned` at once.
+#[proc_macro_derive(Ownable, attributes(ownable))]
+pub fn ownable(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive_ownable(&input).into()
+}
+
 /// Derive `to_borrowed`.
 #[proc_macro_derive(ToBorrowed, attributes(ownable))]
 pub fn to_borrowed(tokens: TokenStream) -> TokenStream {
@@ -39,3 +50,45 @@ pub fn into_owned(tokens: TokenStream) -> TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
     derive(&input, Mode::IntoOwned).into()
 }
+
+/// Derive `into_owned_compact`.
+#[proc_macro_derive(IntoOwnedCompact, attributes(ownable))]
+pub fn into_owned_compact(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive(&input, Mode::IntoOwnedCompact).into()
+}
+
+/// Derive `into_leaked`.
+#[proc_macro_derive(IntoLeaked, attributes(ownable))]
+pub fn into_leaked(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive(&input, Mode::IntoLeaked).into()
+}
+
+/// Derive `rebase`.
+#[proc_macro_derive(Rebase, attributes(ownable))]
+pub fn rebase(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive(&input, Mode::Rebase).into()
+}
+
+/// Derive `for_each_borrow`.
+#[proc_macro_derive(ForEachBorrow, attributes(ownable))]
+pub fn for_each_borrow(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive(&input, Mode::ForEachBorrow).into()
+}
+
+/// Derive `visit`.
+#[proc_macro_derive(OwnableVisit, attributes(ownable))]
+pub fn visit(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive(&input, Mode::OwnableVisit).into()
+}
+
+/// Derive `map_cows`.
+#[proc_macro_derive(MapCows, attributes(ownable))]
+pub fn map_cows(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+    derive(&input, Mode::MapCows).into()
+}
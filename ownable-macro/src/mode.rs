@@ -1,19 +1,48 @@
-use proc_macro2::TokenStream;
+use crate::attribute::{FunctionAttribute, InlineAttribute};
+use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
+use syn::{Lifetime, Path, Type};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum Mode {
     ToBorrowed,
     ToOwned,
     IntoOwned,
+    IntoOwnedCompact,
+    IntoLeaked,
+    Rebase,
+    ForEachBorrow,
+    OwnableVisit,
+    MapCows,
 }
 
 impl Mode {
-    pub(crate) fn name(self) -> TokenStream {
+    pub(crate) const ALL: [Mode; 9] = [
+        Mode::ToBorrowed,
+        Mode::ToOwned,
+        Mode::IntoOwned,
+        Mode::IntoOwnedCompact,
+        Mode::IntoLeaked,
+        Mode::Rebase,
+        Mode::ForEachBorrow,
+        Mode::OwnableVisit,
+        Mode::MapCows,
+    ];
+
+    // The modes derived by `Ownable`.
+    pub(crate) const OWNABLE: [Mode; 3] = [Mode::IntoOwned, Mode::ToBorrowed, Mode::ToOwned];
+
+    pub(crate) fn name(self, krate: &Path) -> TokenStream {
         match self {
-            Mode::ToBorrowed => quote!(::ownable::traits::ToBorrowed),
-            Mode::ToOwned => quote!(::ownable::traits::ToOwned),
-            Mode::IntoOwned => quote!(::ownable::traits::IntoOwned),
+            Mode::ToBorrowed => quote!(#krate::traits::ToBorrowed),
+            Mode::ToOwned => quote!(#krate::traits::ToOwned),
+            Mode::IntoOwned => quote!(#krate::traits::IntoOwned),
+            Mode::IntoOwnedCompact => quote!(#krate::traits::IntoOwnedCompact),
+            Mode::IntoLeaked => quote!(#krate::traits::IntoLeaked),
+            Mode::Rebase => quote!(#krate::traits::Rebase),
+            Mode::ForEachBorrow => quote!(#krate::traits::ForEachBorrow),
+            Mode::OwnableVisit => quote!(#krate::traits::OwnableVisit),
+            Mode::MapCows => quote!(#krate::traits::MapCows),
         }
     }
     pub(crate) fn function(self) -> TokenStream {
@@ -21,10 +50,172 @@ impl Mode {
             Mode::ToBorrowed => quote!(to_borrowed),
             Mode::ToOwned => quote!(to_owned),
             Mode::IntoOwned => quote!(into_owned),
+            Mode::IntoOwnedCompact => quote!(into_owned_compact),
+            Mode::IntoLeaked => quote!(into_leaked),
+            Mode::Rebase => quote!(rebase),
+            Mode::ForEachBorrow => quote!(for_each_borrow),
+            Mode::OwnableVisit => quote!(visit),
+            Mode::MapCows => quote!(map_cows),
+        }
+    }
+    pub(crate) fn output(self) -> TokenStream {
+        match self {
+            Mode::IntoLeaked => quote!(Leaked),
+            Mode::Rebase => quote!(Rebased),
+            _ => quote!(Owned),
+        }
+    }
+    pub(crate) fn with_lifetime(self) -> bool {
+        matches!(self, Mode::Rebase)
+    }
+    // Whether the value is only inspected (instead of creating a new one)
+    pub(crate) fn read_only(self) -> bool {
+        matches!(self, Mode::ForEachBorrow | Mode::OwnableVisit)
+    }
+    // Whether the result is the owned type (which can be replaced by `owned_type`)
+    pub(crate) fn owned(self) -> bool {
+        matches!(
+            self,
+            Mode::ToOwned | Mode::IntoOwned | Mode::IntoOwnedCompact
+        )
+    }
+    // Whether the result has the same type (i.e. values which are not converted are moved)
+    pub(crate) fn same_type(self) -> bool {
+        matches!(self, Mode::MapCows)
+    }
+    pub(crate) fn parameters(self, krate: &Path, lifetime: &Lifetime) -> TokenStream {
+        match self {
+            Mode::IntoLeaked => {
+                let leaker = Self::argument("leaker");
+                quote!(, #leaker: &mut #krate::Leaker)
+            }
+            Mode::Rebase => {
+                let (old, new) = (Self::argument("old"), Self::argument("new"));
+                quote!(, #old: &[u8], #new: &#lifetime [u8])
+            }
+            Mode::ForEachBorrow => {
+                let f = Self::argument("f");
+                quote!(, #f: &mut dyn ::core::ops::FnMut(::core::ops::Range<usize>))
+            }
+            Mode::OwnableVisit => {
+                let (path, visitor) = (Self::argument("path"), Self::argument("visitor"));
+                quote! {
+                    , #path: &#krate::visit::FieldPath<'_>,
+                    #visitor: &mut impl #krate::visit::Visitor
+                }
+            }
+            Mode::MapCows => {
+                let mapper = Self::argument("mapper");
+                quote!(, #mapper: &mut impl #krate::map::CowMapper)
+            }
+            _ => quote!(),
+        }
+    }
+    pub(crate) fn arguments(self) -> TokenStream {
+        match self {
+            Mode::IntoLeaked => {
+                let leaker = Self::argument("leaker");
+                quote!(, #leaker)
+            }
+            Mode::Rebase => {
+                let (old, new) = (Self::argument("old"), Self::argument("new"));
+                quote!(, #old, #new)
+            }
+            Mode::ForEachBorrow => {
+                let f = Self::argument("f");
+                quote!(, #f)
+            }
+            Mode::OwnableVisit => {
+                let (path, visitor) = (Self::argument("path"), Self::argument("visitor"));
+                quote!(, #path, #visitor)
+            }
+            Mode::MapCows => {
+                let mapper = Self::argument("mapper");
+                quote!(, #mapper)
+            }
+            _ => quote!(),
+        }
+    }
+    // The arguments for the call of a field, i.e. the path is extended by the variant and field.
+    pub(crate) fn field_arguments(
+        self,
+        krate: &Path,
+        variant: Option<&Ident>,
+        field: &str,
+    ) -> TokenStream {
+        if let Mode::OwnableVisit = self {
+            let (path, visitor) = (Self::argument("path"), Self::argument("visitor"));
+            let segment = quote!(#krate::visit::PathSegment);
+            let variant = variant.map(|v| {
+                let v = v.to_string();
+                quote!(.join(#segment::Variant(#v)))
+            });
+            quote!(, &#path #variant .join(#segment::Field(#field)), #visitor)
+        } else {
+            self.arguments()
+        }
+    }
+    // The bound for a type, which is converted into the target type.
+    pub(crate) fn bound(
+        self,
+        krate: &Path,
+        source: &Type,
+        target: &Type,
+        lifetime: &Lifetime,
+    ) -> TokenStream {
+        let name = self.name(krate);
+        let output = self.output();
+        match self {
+            Mode::ToBorrowed => quote!(#source: #name<#lifetime>),
+            Mode::ToOwned | Mode::IntoOwned | Mode::IntoOwnedCompact | Mode::IntoLeaked => {
+                quote!(#source: #name<#output = #target>)
+            }
+            Mode::Rebase => quote!(#source: #name<#lifetime, #output = #target>),
+            Mode::ForEachBorrow | Mode::OwnableVisit | Mode::MapCows => quote!(#source: #name),
+        }
+    }
+    // Inherent functions for the provided functions of the trait (if not disabled or renamed with
+    // `function`).
+    pub(crate) fn provided_functions(
+        self,
+        krate: &Path,
+        function: &FunctionAttribute,
+        inline: InlineAttribute,
+    ) -> TokenStream {
+        match (self, function.configured_name(quote!(borrows_from))) {
+            (Mode::ForEachBorrow, Some(name)) => {
+                let trait_name = self.name(krate);
+                let vis = function.vis();
+                quote! {
+                    #[doc=" Whether all borrowed values are within `buffer`."]
+                    #inline
+                    #vis fn #name(&self, buffer: &[u8]) -> bool {
+                        #trait_name::borrows_from(self, buffer)
+                    }
+                }
+            }
+            _ => TokenStream::new(),
+        }
+    }
+    // The arguments are hygienic, thus they don't collide with the names of fields.
+    fn argument(name: &str) -> Ident {
+        Ident::new(name, Span::mixed_site())
+    }
+    pub(crate) fn parallel(self, krate: &Path) -> Option<(TokenStream, TokenStream)> {
+        match self {
+            Mode::ToBorrowed
+            | Mode::IntoOwnedCompact
+            | Mode::IntoLeaked
+            | Mode::Rebase
+            | Mode::ForEachBorrow
+            | Mode::OwnableVisit
+            | Mode::MapCows => None,
+            Mode::ToOwned => Some((quote!(#krate::traits::ParToOwned), quote!(par_to_owned))),
+            Mode::IntoOwned => Some((quote!(#krate::traits::ParIntoOwned), quote!(par_into_owned))),
         }
     }
     pub(crate) fn as_ref(self) -> TokenStream {
-        if let Mode::IntoOwned = self {
+        if let Mode::IntoOwned | Mode::IntoOwnedCompact | Mode::IntoLeaked | Mode::MapCows = self {
             quote!()
         } else {
             quote!(&)
@@ -47,6 +238,32 @@ impl Mode {
                 \n\
                 This is always a deep copy of the structure."
             }
+            Mode::IntoOwnedCompact => {
+                " Copy the structure and clone the original values if it's not owned, excess capacity of the\n\
+                owned values is released.\n\
+                \n\
+                This is always a deep copy of the structure."
+            }
+            Mode::IntoLeaked => {
+                " Copy the structure and leak the original values (once), which are then referenced.\n\
+                \n\
+                This is always a deep copy of the structure."
+            }
+            Mode::Rebase => {
+                " Copy the structure and reference the values, which are borrowed from the buffer `old`, at the\n\
+                same offsets in the buffer `new` (all other values are cloned).\n\
+                \n\
+                This is always a deep copy of the structure."
+            }
+            Mode::ForEachBorrow => {
+                " Call `f` with the address range of each borrowed value."
+            }
+            Mode::OwnableVisit => {
+                " Call the `visitor` for each `Cow` value, `path` is the path to this value."
+            }
+            Mode::MapCows => {
+                " Rebuild the structure with each `Cow<str>` and `Cow<[u8]>` passed through the `mapper`."
+            }
         }
     }
 }
@@ -0,0 +1,331 @@
+use crate::attribute::{DeriveAttribute, FieldAttribute, OrAssign};
+use crate::derive::Derive;
+use crate::mode::Mode;
+use darling::FromAttributes;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{
+    parse_quote, Attribute, Data, DeriveInput, Fields, GenericArgument, Generics, Lifetime,
+    LifetimeParam, PathArguments, Type, TypePath, TypeReference,
+};
+
+impl Derive<'_> {
+    // The companion type, if it's used by the current mode.
+    pub(crate) fn owned_type(&self) -> Option<&Ident> {
+        if self.mode.owned() {
+            self.attribute.owned_type.as_ref()
+        } else {
+            None
+        }
+    }
+
+    // The type which is created in the current mode.
+    pub(crate) fn target(&self) -> &Ident {
+        self.owned_type().unwrap_or(self.ident)
+    }
+
+    // The payload `T` of a field with the type `Cow<'a, T>`.
+    pub(crate) fn cow_payload(ty: &Type) -> Option<&Type> {
+        let segment = match ty {
+            Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+            _ => return None,
+        };
+        let arguments = match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => arguments,
+            _ => return None,
+        };
+        match (
+            segment.ident == "Cow",
+            arguments.args.len(),
+            arguments.args.last(),
+        ) {
+            (true, 2, Some(GenericArgument::Type(ty))) => Some(ty),
+            _ => None,
+        }
+    }
+
+    // Generate the companion type (with `owned_type`) and the conversion back.
+    pub(crate) fn generate_owned_type(&mut self, input: &DeriveInput) -> TokenStream {
+        let owned_type = match self.attribute.owned_type.clone() {
+            Some(owned_type) => owned_type,
+            None => return TokenStream::new(),
+        };
+
+        let krate = &self.krate.clone();
+        let lifetime_static = Lifetime::new("'static", Span::call_site());
+        let lifetime_our = self.new_lifetime();
+        let generics_definition = self.generate_generics(None);
+        let (_, generics_owned, _) = generics_definition.split_for_impl();
+        let generics_our = self.generate_generics(Some(&lifetime_our));
+        let mut generics_impl = generics_definition.clone();
+        generics_impl
+            .params
+            .insert(0, LifetimeParam::new(lifetime_our.clone()).into());
+        let mut where_owned = self.generate_where(Some(&lifetime_static));
+        where_owned.predicates.extend(self.generate_bounds(
+            Mode::IntoOwned,
+            Some(&lifetime_static),
+            &lifetime_static,
+        ));
+        let mut where_impl = where_owned.clone();
+        where_impl
+            .predicates
+            .extend(self.generate_where(Some(&lifetime_our)).predicates);
+        for t in self.generics.type_params() {
+            let t = &t.ident;
+            where_impl.predicates.push(parse_quote!(#t: #lifetime_our));
+        }
+        for ty in self.bound_types(Mode::IntoOwned) {
+            let mut ty_static = ty.clone();
+            self.set_lifetimes(&lifetime_static)
+                .visit_type_mut(&mut ty_static);
+            let mut ty_our = ty;
+            self.set_lifetimes(&lifetime_our)
+                .visit_type_mut(&mut ty_our);
+            where_impl.predicates.push(parse_quote!(
+                #ty_static: #krate::traits::AsBorrowed<#lifetime_our, Borrowed = #ty_our>
+            ));
+        }
+
+        let vis = &input.vis;
+        let name = self.ident;
+        let doc = format!(" The owned version of [`{name}`].");
+        let (definition, view) = match &input.data {
+            Data::Struct(data) => {
+                let (fields, pattern, view) = self.owned_fields(&[], &data.fields);
+                let definition = match &data.fields {
+                    Fields::Named(_) => quote! { #where_owned #fields },
+                    Fields::Unnamed(_) => quote! { #fields #where_owned; },
+                    Fields::Unit => quote! { #where_owned; },
+                };
+                (
+                    quote! { struct #owned_type #generics_definition #definition },
+                    quote! {
+                        let #owned_type #pattern = self;
+                        #name #view
+                    },
+                )
+            }
+            Data::Enum(data) => {
+                let mut variants = Vec::new();
+                let mut matches = Vec::new();
+                for v in &data.variants {
+                    let (fields, pattern, view) = self.owned_fields(&v.attrs, &v.fields);
+                    let variant_name = &v.ident;
+                    let discriminant = v.discriminant.as_ref().map(|(eq, d)| quote! {#eq #d});
+                    variants.push(quote! { #variant_name #fields #discriminant });
+                    matches.push(quote! {
+                        #owned_type :: #variant_name #pattern => #name :: #variant_name #view
+                    });
+                }
+                (
+                    quote! { enum #owned_type #generics_definition #where_owned { #(#variants),* } },
+                    quote! {
+                        match self {
+                            #(#matches),*
+                        }
+                    },
+                )
+            }
+            Data::Union(_) => {
+                return self.error_with(
+                    input,
+                    "`owned_type` isn't supported for unions",
+                    TokenStream::new(),
+                )
+            }
+        };
+
+        quote! {
+            #[doc=#doc]
+            #vis #definition
+
+            impl #generics_impl #krate::traits::AsBorrowed<#lifetime_our> for #owned_type #generics_owned #where_impl
+            {
+                type Borrowed = #name #generics_our;
+
+                fn as_borrowed(&#lifetime_our self) -> Self::Borrowed {
+                    #view
+                }
+            }
+        }
+    }
+
+    // The fields of the companion type, the pattern to match them and the conversion back.
+    fn owned_fields(
+        &mut self,
+        variant_attrs: &[Attribute],
+        fields: &Fields,
+    ) -> (TokenStream, TokenStream, TokenStream) {
+        let mut definitions = Vec::new();
+        let mut pattern = Vec::new();
+        let mut view = Vec::new();
+        for (i, field) in fields.iter().enumerate() {
+            let mut field_attribute = self.handle(FieldAttribute::from_attributes(&field.attrs));
+            field_attribute.or_assign(&self.handle(FieldAttribute::from_attributes(variant_attrs)));
+            field_attribute.or_assign(self.attribute);
+
+            let binding = field
+                .ident
+                .clone()
+                .unwrap_or_else(|| Ident::new(&format!("arg{i}"), Span::call_site()));
+            let (ty, value) = self.owned_field(&field_attribute, &field.ty, &binding);
+            let vis = &field.vis;
+            if let Some(name) = &field.ident {
+                definitions.push(quote! { #vis #name: #ty });
+                view.push(quote! { #name: #value });
+            } else {
+                definitions.push(quote! { #vis #ty });
+                view.push(value);
+            }
+            pattern.push(binding);
+        }
+
+        match fields {
+            Fields::Named(_) => (
+                quote! {{ #(#definitions),* }},
+                quote! {{ #(#pattern),* }},
+                quote! {{ #(#view),* }},
+            ),
+            Fields::Unnamed(_) => (
+                quote! {( #(#definitions),* )},
+                quote! {( #(#pattern),* )},
+                quote! {( #(#view),* )},
+            ),
+            Fields::Unit => (quote!(), quote!(), quote!()),
+        }
+    }
+
+    // The type of the field in the companion type and how it's converted back.
+    fn owned_field(
+        &mut self,
+        field_attribute: &FieldAttribute,
+        ty: &Type,
+        binding: &Ident,
+    ) -> (TokenStream, TokenStream) {
+        let krate = &self.krate;
+        let mut ty_static = ty.clone();
+        let lifetime_static = Lifetime::new("'static", Span::call_site());
+        self.set_lifetimes(&lifetime_static)
+            .visit_type_mut(&mut ty_static);
+        if let Type::Reference(TypeReference {
+            mutability: Some(_),
+            ..
+        }) = ty
+        {
+            (
+                quote! { #ty },
+                self.error_with(
+                    ty,
+                    "a mutable reference can't be copied into the type of `owned_type`",
+                    quote! { todo!() },
+                ),
+            )
+        } else if self.attribute.is_reference_type(ty) {
+            (quote! { #ty }, quote! { *#binding })
+        } else if let Some(replacement) = field_attribute.replacement(Mode::IntoOwned) {
+            // the field is replaced in both directions
+            (
+                quote! { #ty_static },
+                quote! {{ let _ = #binding; #replacement }},
+            )
+        } else if field_attribute.clone.unwrap_or(false)
+            || field_attribute.with(Mode::IntoOwned).is_some()
+            || self.attribute.is_kept_type(ty)
+        {
+            // the custom function is expected to return the type with static lifetimes
+            (
+                quote! { #ty_static },
+                quote! { ::core::clone::Clone::clone(#binding) },
+            )
+        } else if let Some(payload) = Self::cow_payload(&ty_static) {
+            (
+                quote! { <#payload as #krate::__private::ToOwned>::Owned },
+                quote! {
+                    #krate::__private::Cow::Borrowed(::core::borrow::Borrow::borrow(#binding))
+                },
+            )
+        } else {
+            let value = quote! { #krate::traits::AsBorrowed::as_borrowed(#binding) };
+            if self.is_independent(ty) {
+                (quote! { #ty }, value)
+            } else {
+                (
+                    quote! { <#ty_static as #krate::traits::IntoOwned>::Owned },
+                    value,
+                )
+            }
+        }
+    }
+
+    // Whether the type doesn't depend on the lifetimes or type parameters (of the derived type).
+    fn is_independent(&self, ty: &Type) -> bool {
+        let mut dependent = Dependent {
+            attribute: self.attribute,
+            generics: self.generics,
+            types: self.generics.type_params().map(|t| &t.ident).collect(),
+            found: false,
+        };
+        dependent.visit_type(ty);
+        !dependent.found
+    }
+}
+
+// Replace the lifetimes of the type (except the kept lifetimes) with another one, other lifetimes
+// (e.g. `'static` or of `for<'x>`) are not changed.
+pub(crate) struct SetLifetimes<'a> {
+    attribute: &'a DeriveAttribute,
+    generics: &'a Generics,
+    lifetime: &'a Lifetime,
+}
+
+impl Derive<'_> {
+    pub(crate) fn set_lifetimes<'a>(&'a self, lifetime: &'a Lifetime) -> SetLifetimes<'a> {
+        SetLifetimes {
+            attribute: self.attribute,
+            generics: self.generics,
+            lifetime,
+        }
+    }
+}
+
+impl VisitMut for SetLifetimes<'_> {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut Lifetime) {
+        if DeriveAttribute::contains_lifetime(self.generics, &lifetime.ident.to_string())
+            && !self.attribute.is_kept_lifetime(&lifetime.ident)
+        {
+            *lifetime = self.lifetime.clone();
+        }
+    }
+}
+
+// Search for lifetimes of the type (except the kept lifetimes) and type parameters.
+struct Dependent<'a> {
+    attribute: &'a DeriveAttribute,
+    generics: &'a Generics,
+    types: Vec<&'a Ident>,
+    found: bool,
+}
+
+impl<'ast> Visit<'ast> for Dependent<'_> {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        if DeriveAttribute::contains_lifetime(self.generics, &lifetime.ident.to_string())
+            && !self.attribute.is_kept_lifetime(&lifetime.ident)
+        {
+            self.found = true;
+        }
+    }
+
+    fn visit_type_path(&mut self, type_path: &'ast TypePath) {
+        if type_path.qself.is_none() {
+            if let Some(ident) = type_path.path.get_ident() {
+                if self.types.contains(&ident) {
+                    self.found = true;
+                }
+            }
+        }
+        syn::visit::visit_type_path(self, type_path);
+    }
+}
@@ -14,7 +14,11 @@ impl Derive<'_> {
     }
 
     fn derive_struct_unit(&self) -> TokenStream {
-        let name = self.ident;
-        quote! {#name}
+        if self.mode.read_only() {
+            quote! {{}}
+        } else {
+            let name = self.target();
+            quote! {#name}
+        }
     }
 }
@@ -0,0 +1,41 @@
+use crate::attribute::FieldAttribute;
+use crate::derive::Derive;
+use darling::FromAttributes;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DataUnion;
+
+impl Derive<'_> {
+    // The active field of a union is unknown, thus the union is always copied (or moved) as a whole.
+    pub(crate) fn derive_union(&mut self, data: &DataUnion) -> TokenStream {
+        for field in &data.fields.named {
+            // the fields are bound to be `Copy` (except with `clone`), see `infer_bounds`
+            let field_attribute = self.handle(FieldAttribute::from_attributes(&field.attrs));
+            if field_attribute.is_skipped() || field_attribute.with(self.mode).is_some() {
+                self.error(
+                    field,
+                    "the fields of a union can only be copied (or cloned with `clone`)",
+                );
+            }
+        }
+        if !self.mode.read_only() && !self.mode.same_type() {
+            for l in self.generics.lifetimes() {
+                if !self.attribute.is_kept_lifetime(&l.lifetime.ident) {
+                    self.error(
+                        l,
+                        "the lifetimes of a union can't be changed, they have to be kept (see `reference` and `keep`)",
+                    );
+                }
+            }
+        }
+
+        let inner = if self.mode.read_only() {
+            quote! {{}}
+        } else if self.mode.as_ref().is_empty() {
+            quote! {self}
+        } else {
+            quote! {::core::clone::Clone::clone(self)}
+        };
+        self.generate(&inner)
+    }
+}
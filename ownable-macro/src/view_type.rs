@@ -0,0 +1,285 @@
+use crate::derive::Derive;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::visit_mut::VisitMut;
+use syn::{
+    parse_quote, Data, DeriveInput, Field, Fields, GenericArgument, GenericParam, Generics,
+    Lifetime, LifetimeParam, Path, PathArguments, PathSegment, Type, TypePath, TypeReference,
+    WherePredicate,
+};
+
+impl Derive<'_> {
+    // Generate the view type (with `view_type`) and the function `view`.
+    pub(crate) fn generate_view_type(&mut self, input: &DeriveInput) -> TokenStream {
+        let view_type = match self.attribute.view_type.clone() {
+            Some(view_type) => view_type,
+            None => return TokenStream::new(),
+        };
+        if self.packed || matches!(input.data, Data::Union(_)) {
+            // the fields of packed structs can't be referenced and those of unions are unknown
+            return self.error_with(
+                input,
+                "`view_type` isn't supported for packed structs and unions",
+                TokenStream::new(),
+            );
+        }
+        if self.verify_view_fields(input) {
+            return TokenStream::new();
+        }
+
+        let krate = &self.krate.clone();
+        let lifetime_our = self.new_lifetime();
+        // the view has only the reference lifetimes (and all others are replaced by our lifetime)
+        let mut generics_view = self.generate_generics(None);
+        generics_view
+            .params
+            .insert(0, LifetimeParam::new(lifetime_our.clone()).into());
+        let mut where_view = self.generate_where(Some(&lifetime_our));
+        where_view
+            .predicates
+            .extend(Self::outlives(&generics_view, &lifetime_our));
+        let (generics_definition, generics_our, _) = generics_view.split_for_impl();
+        let mut generics_impl = self.generics.clone();
+        generics_impl
+            .params
+            .insert(0, LifetimeParam::new(lifetime_our.clone()).into());
+        let mut where_impl = where_view.clone();
+        where_impl
+            .predicates
+            .extend(Self::outlives(self.generics, &lifetime_our));
+        let (generics_impl, _, _) = generics_impl.split_for_impl();
+        let (_, generics_self, _) = self.generics.split_for_impl();
+
+        let vis = &input.vis;
+        let name = self.ident;
+        let doc = format!(" The view of [`{name}`], which references all values.");
+        let (definition, view) = match &input.data {
+            Data::Struct(data) => {
+                let (fields, pattern, view) = self.view_fields(&data.fields, &lifetime_our);
+                let definition = match &data.fields {
+                    Fields::Named(_) => quote! { #where_view #fields },
+                    Fields::Unnamed(_) => quote! { #fields #where_view; },
+                    Fields::Unit => quote! { #where_view; },
+                };
+                (
+                    quote! { struct #view_type #generics_definition #definition },
+                    quote! {
+                        let #name #pattern = self;
+                        #view_type #view
+                    },
+                )
+            }
+            Data::Enum(data) => {
+                let mut variants = Vec::new();
+                let mut matches = Vec::new();
+                for v in &data.variants {
+                    let (fields, pattern, view) = self.view_fields(&v.fields, &lifetime_our);
+                    let variant_name = &v.ident;
+                    variants.push(quote! { #variant_name #fields });
+                    matches.push(quote! {
+                        #name :: #variant_name #pattern => #view_type :: #variant_name #view
+                    });
+                }
+                (
+                    quote! { enum #view_type #generics_definition #where_view { #(#variants),* } },
+                    quote! {
+                        match self {
+                            #(#matches),*
+                        }
+                    },
+                )
+            }
+            // unions are already reported
+            Data::Union(_) => return TokenStream::new(),
+        };
+
+        let function = self.generate_view_function(&view_type);
+
+        quote! {
+            #[doc=#doc]
+            #vis #definition
+
+            impl #generics_impl #krate::traits::AsBorrowed<#lifetime_our> for #name #generics_self #where_impl
+            {
+                type Borrowed = #view_type #generics_our;
+
+                fn as_borrowed(&#lifetime_our self) -> Self::Borrowed {
+                    #view
+                }
+            }
+
+            #function
+        }
+    }
+
+    // Report the mutable references (which can't be copied into the view), whether there are any.
+    fn verify_view_fields(&mut self, input: &DeriveInput) -> bool {
+        let fields: Vec<&Field> = match &input.data {
+            Data::Struct(data) => data.fields.iter().collect(),
+            Data::Enum(data) => data.variants.iter().flat_map(|v| &v.fields).collect(),
+            Data::Union(_) => Vec::new(),
+        };
+        let mut mutable = false;
+        for field in fields {
+            if let Type::Reference(TypeReference {
+                mutability: Some(_),
+                ..
+            }) = &field.ty
+            {
+                mutable = true;
+                self.error(
+                    &field.ty,
+                    "a mutable reference can't be copied into the type of `view_type`",
+                );
+            }
+        }
+        mutable
+    }
+
+    // The inherent function `view` (if not disabled or renamed with `function`).
+    fn generate_view_function(&self, view_type: &Ident) -> TokenStream {
+        let function = match self.attribute.function.view_name() {
+            Some(function) => function,
+            None => return TokenStream::new(),
+        };
+        let krate = &self.krate;
+        let name = self.ident;
+        let vis = self.attribute.function.vis();
+        let inline = self.attribute.inline;
+        let (generics_original, generics_self, where_original) = self.generics.split_for_impl();
+        let mut generics_placeholder = self.generate_generics(None);
+        generics_placeholder.params.insert(
+            0,
+            LifetimeParam::new(Lifetime::new("'_", Span::call_site())).into(),
+        );
+        let (_, generics_placeholder, _) = generics_placeholder.split_for_impl();
+
+        quote! {
+            impl #generics_original #name #generics_self #where_original
+            {
+                #[doc=" Reference all values, this never allocates."]
+                #inline
+                #vis fn #function(&self) -> #view_type #generics_placeholder {
+                    #krate::traits::AsBorrowed::as_borrowed(self)
+                }
+            }
+        }
+    }
+
+    // The fields of the view type, the pattern to match the original and the conversion.
+    fn view_fields(
+        &self,
+        fields: &Fields,
+        lifetime: &Lifetime,
+    ) -> (TokenStream, TokenStream, TokenStream) {
+        let mut definitions = Vec::new();
+        let mut pattern = Vec::new();
+        let mut view = Vec::new();
+        for (i, field) in fields.iter().enumerate() {
+            let binding = field
+                .ident
+                .clone()
+                .unwrap_or_else(|| Ident::new(&format!("arg{i}"), Span::call_site()));
+            let (ty, value) = if self.attribute.is_reference_type(&field.ty) {
+                (field.ty.clone(), quote! { *#binding })
+            } else {
+                // the value is converted by deref coercion
+                let mut ty = Self::view_field_type(&field.ty, lifetime);
+                self.set_lifetimes(lifetime).visit_type_mut(&mut ty);
+                (ty, quote! { #binding })
+            };
+            let vis = &field.vis;
+            if let Some(name) = &field.ident {
+                definitions.push(quote! { #vis #name: #ty });
+                view.push(quote! { #name: #value });
+            } else {
+                definitions.push(quote! { #vis #ty });
+                view.push(value);
+            }
+            pattern.push(binding);
+        }
+
+        match fields {
+            Fields::Named(_) => (
+                quote! {{ #(#definitions),* }},
+                quote! {{ #(#pattern),* }},
+                quote! {{ #(#view),* }},
+            ),
+            Fields::Unnamed(_) => (
+                quote! {( #(#definitions),* )},
+                quote! {( #(#pattern),* )},
+                quote! {( #(#view),* )},
+            ),
+            Fields::Unit => (quote!(), quote!(), quote!()),
+        }
+    }
+
+    // The type of a field in the view: `String` becomes `&str`, `Vec<T>` becomes `&[T]`,
+    // `Box<T>` and `Cow<T>` become `&T` and all others `U` become `&U`.
+    fn view_field_type(ty: &Type, lifetime: &Lifetime) -> Type {
+        if let Type::Path(TypePath { qself: None, path }) = ty {
+            if let Some(segment) = Self::std_type(path) {
+                let arguments = match &segment.arguments {
+                    PathArguments::AngleBracketed(arguments) => arguments.args.iter().collect(),
+                    PathArguments::None | PathArguments::Parenthesized(_) => Vec::new(),
+                };
+                let ident = segment.ident.to_string();
+                match (ident.as_str(), arguments.as_slice()) {
+                    ("String", []) => return parse_quote!(&#lifetime str),
+                    ("Vec", [GenericArgument::Type(t)]) => return parse_quote!(&#lifetime [#t]),
+                    ("Box", [GenericArgument::Type(t)])
+                    | ("Cow", [GenericArgument::Lifetime(_), GenericArgument::Type(t)]) => {
+                        return parse_quote!(&#lifetime #t);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        parse_quote!(&#lifetime #ty)
+    }
+
+    // The last segment, if the path is just a name or the full path of `String`, `Vec`, `Box` or
+    // `Cow` (e.g. `std::vec::Vec` but not `my::Vec`).
+    fn std_type(path: &Path) -> Option<&PathSegment> {
+        const MODULES: [(&str, &str); 4] = [
+            ("string", "String"),
+            ("vec", "Vec"),
+            ("boxed", "Box"),
+            ("borrow", "Cow"),
+        ];
+
+        let segments: Vec<&PathSegment> = path.segments.iter().collect();
+        match segments.as_slice() {
+            [segment] if path.leading_colon.is_none() => Some(segment),
+            [krate, module, segment]
+                if (krate.ident == "std" || krate.ident == "alloc")
+                    && krate.arguments.is_none()
+                    && module.arguments.is_none()
+                    && MODULES
+                        .iter()
+                        .any(|(m, t)| module.ident == m && segment.ident == t) =>
+            {
+                Some(segment)
+            }
+            _ => None,
+        }
+    }
+
+    // All lifetimes and types of the generics outlive `lifetime`.
+    fn outlives<'g>(
+        generics: &'g Generics,
+        lifetime: &'g Lifetime,
+    ) -> impl Iterator<Item = WherePredicate> + 'g {
+        generics.params.iter().filter_map(move |p| match p {
+            GenericParam::Lifetime(l) if l.lifetime != *lifetime => {
+                let l = &l.lifetime;
+                Some(parse_quote!(#l: #lifetime))
+            }
+            GenericParam::Type(t) => {
+                let t = &t.ident;
+                Some(parse_quote!(#t: #lifetime))
+            }
+            GenericParam::Lifetime(_) | GenericParam::Const(_) => None,
+        })
+    }
+}
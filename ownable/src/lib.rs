@@ -60,36 +60,77 @@
 //!
 //! But actually each function only calls a function of [traits](crate::traits), which are derived.
 //!
+//! The three derives can also be combined with `#[derive(Ownable)]`, which checks the attributes
+//! only once (and reports errors once). Single ones can be disabled with
+//! `#[ownable(into_owned = false)]`, `#[ownable(to_borrowed = false)]` or
+//! `#[ownable(to_owned = false)]` (e.g. to implement it by hand), the single derives reject these.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::Ownable;
+//! #[derive(Ownable)]
+//! #[ownable(to_owned = false)]
+//! pub struct Type<'a> {
+//!   cow: Cow<'a, str>,
+//! }
+//! ```
+//!
 //! If the derive does not work it can be implemented by hand and still derived for types which use it.
 //!
 //! # Generics
 //!
 //! The derive macro supports all kinds of generics: lifetimes, types, consts. And the first
-//! two with bounds and all as many times as you want.
+//! two with bounds and all as many times as you want, also with defaults and where-clauses
+//! (including higher-ranked bounds like `for<'x> F: Fn(&'x str)` and outlives relations like
+//! `'a: 'b`). The converted lifetimes of the bounds are replaced by the lifetime of the result.
+//!
+//! The bounds of type parameters are inferred from the fields, which use them directly or within
+//! the supported containers (e.g. `Vec<T>` requires `T: IntoOwned<Owned = T>` for `into_owned`).
+//! Projections like `<T as Trait<'a>>::Assoc` are bound the same way (e.g. with
+//! `<T as Trait<'a>>::Assoc: IntoOwned<Owned = <T as Trait<'static>>::Assoc>`), which works best
+//! with a higher-ranked bound `for<'x> T: Trait<'x>`. Type parameters which are only used in `Cow`
+//! payloads, `PhantomData`, references or other types are not bound, see [bound](#bound) to set
+//! them.
+//!
+//! `ToBorrowed` can also be derived for types without lifetimes, e.g. `struct Node<T>` with
+//! `impl<'o, T: ToBorrowed<'o>> ToBorrowed<'o> for Node<T>`, which is then used with
+//! `Node<Cow<'a, str>>`.
 //!
 //! # References
 //!
 //! References are not supported out of the box, because they can't be changed into an owned type.
 //!
-//! But it's possible to specify which lifetime(s) are used solely for references and then those
-//! will be always copied (the reference) and thus the lifetime is not changed.
+//! But lifetime(s) can be used solely for references and then those will be always copied (the
+//! reference) and thus the lifetime is not changed.
+//!
+//! A lifetime which is used by references (`&'b T`) but nowhere else (neither by a `Cow` nor by
+//! another type, except the type itself) is inferred to be such a lifetime. A lifetime which is
+//! (also) used by another type is never inferred, even if that type uses it solely for
+//! references, thus it has to be marked with [reference](#reference) (as well as all lifetimes
+//! which are inferred incorrectly).
 //!
-//! Please note that not only the type containing a reference but also types containing such a
-//! type are required to be marked.
+//! The references can also be nested within other types (e.g. `Option<&'b str>`, `Vec<&'b T>` or
+//! `(&'b str, Cow<'a, str>)`), since all traits are implemented for `&T` by copying the reference.
+//! These impls are new in 2.0, thus impls of the traits for references of own types (e.g.
+//! `impl IntoOwned for &MyType`) are not possible anymore.
+//!
+//! Mutable references (`&'b mut T`) can't be copied, they are only moved by the consuming
+//! functions (`into_owned`, `into_owned_compact`, `into_leaked` and `map_cows`) and ignored by the
+//! inspecting ones (`for_each_borrow` and `visit`). Deriving the others is refused.
 //!
 //! ## Example
 //!
 //! ```rust
 //! # use std::borrow::Cow;
 //! # use ownable::{IntoOwned, ToBorrowed, ToOwned};
+//! // The lifetime `'b` is inferred.
 //! #[derive(IntoOwned, ToBorrowed, ToOwned)]
-//! #[ownable(reference = "'b")]
 //! pub struct Inner<'a, 'b> {
 //!   cow: Cow<'a, str>,
 //!   referenced: &'b str,
 //! }
 //!
-//! // Also types, containing types with references, must be marked.
+//! // Types, containing types with references, must be marked.
 //! #[derive(IntoOwned, ToBorrowed, ToOwned)]
 //! #[ownable(reference = "'b")]
 //! pub struct Outer<'a, 'b> {
@@ -121,6 +162,227 @@
 //! // The `Outer` will look similar.
 //! ```
 //!
+//! # Packed Structs and Unions
+//!
+//! The fields of packed structs (`#[repr(packed)]`) can't be referenced, thus they are copied out
+//! before they are converted. So all fields have to be `Copy`, only the consuming functions (e.g.
+//! `into_owned`) can move them. `view_type` isn't supported for packed structs.
+//!
+//! The active field of a union is unknown, thus a union is cloned (or moved) as a whole. So the
+//! union has to implement `Clone`, all its fields have to be `Copy` (or marked with
+//! [clone](#clone)) and all its lifetimes have to be kept (see [References](#references) and
+//! [keep](#keep)).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::{IntoOwned, ToBorrowed, ToOwned};
+//! #[derive(Clone, Copy, IntoOwned, ToBorrowed, ToOwned)]
+//! #[repr(C, packed)]
+//! pub struct Header {
+//!   version: u8,
+//!   length: u32,
+//! }
+//!
+//! #[derive(Clone, Copy, IntoOwned, ToBorrowed, ToOwned)]
+//! pub union Value<'b> {
+//!   number: u64,
+//!   text: &'b str,
+//! }
+//!
+//! #[derive(IntoOwned, ToBorrowed, ToOwned)]
+//! #[ownable(keep = "'b")]
+//! pub struct Packet<'a, 'b> {
+//!   header: Header,
+//!   value: Value<'b>,
+//!   payload: Cow<'a, [u8]>,
+//! }
+//! ```
+//!
+//! # Compact
+//!
+//! Owned values (e.g. a `Cow::Owned` from parsing) are moved by `into_owned` and may keep excess
+//! capacity. With the additional derive [`IntoOwnedCompact`](crate::IntoOwnedCompact) the function
+//! `into_owned_compact` releases that capacity of every owned [`String`](::alloc::string::String),
+//! [`Vec`](::alloc::vec::Vec), [`VecDeque`](::alloc::collections::VecDeque) and map while converting.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::{IntoOwned, IntoOwnedCompact};
+//! #[derive(IntoOwned, IntoOwnedCompact)]
+//! pub struct Type<'a> {
+//!   cow: Cow<'a, str>,
+//!   list: Vec<Cow<'a, str>>,
+//! }
+//! ```
+//!
+//! It requires that [`IntoOwned`](crate::IntoOwned) is derived (or implemented) as well.
+//!
+//! # Leak
+//!
+//! For data which is loaded once and kept forever the derive [`IntoLeaked`](crate::IntoLeaked)
+//! converts `Type<'a>` into `Type<'static>` by leaking every `Cow` payload (borrowed ones are
+//! copied first) and referencing it with `Cow::Borrowed`, thus later `to_owned` calls of the
+//! `Cow`s are cheap.
+//!
+//! With [`Leaker::interning`](crate::Leaker::interning) identical strings are only leaked once
+//! (other payloads, e.g. `[u8]`, are always leaked separately).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::{IntoLeaked, Leaker};
+//! #[derive(IntoLeaked)]
+//! pub struct Config<'a> {
+//!   name: Cow<'a, str>,
+//!   tags: Vec<Cow<'a, str>>,
+//! }
+//!
+//! fn load(input: &str) -> Config<'static> {
+//!   let config = Config {
+//!     name: Cow::Borrowed(input),
+//!     tags: vec![Cow::Borrowed(input)],
+//!   };
+//!   config.into_leaked(&mut Leaker::interning())
+//! }
+//! ```
+//!
+//! # Rebase
+//!
+//! When a buffer, from which values are borrowed, is copied (e.g. into an `Arc<[u8]>`) the derive
+//! [`Rebase`](crate::Rebase) allows to reference the same offsets in the new buffer instead of
+//! cloning each value. Values which are not borrowed from the old buffer are cloned.
+//!
+//! Values of the types `Cow<str>`, `Cow<[u8]>` and `Cow<CStr>` are rebased (a `str` only when the
+//! new buffer contains valid UTF-8 at that position, a `CStr` only when it is still terminated by
+//! the same nul). All other `Cow` values (e.g. `Cow<[u32]>` or `Cow<Path>`) are cloned.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::sync::Arc;
+//! # use ownable::Rebase;
+//! #[derive(Rebase)]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   data: Cow<'a, [u8]>,
+//! }
+//!
+//! fn keep<'b>(input: &str, record: &Record<'_>, buffer: &'b Arc<[u8]>) -> Record<'b> {
+//!   record.rebase(input.as_bytes(), buffer)
+//! }
+//! ```
+//!
+//! # Provenance
+//!
+//! The derive [`ForEachBorrow`](crate::ForEachBorrow) reports the address range of each
+//! `Cow::Borrowed` value with `for_each_borrow`, and `borrows_from` checks that all of them are
+//! within a buffer (e.g. to assert that nothing borrows from a temporary).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::ForEachBorrow;
+//! #[derive(ForEachBorrow)]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   tags: Vec<Cow<'a, str>>,
+//! }
+//!
+//! let input = String::from("name tag");
+//! let record = Record {
+//!   name: Cow::Borrowed(&input[..4]),
+//!   tags: vec![Cow::Borrowed(&input[5..]), Cow::Owned(String::from("owned"))],
+//! };
+//! assert!(record.borrows_from(input.as_bytes()));
+//! ```
+//!
+//! # Visit
+//!
+//! The derive [`OwnableVisit`](crate::OwnableVisit) calls a [`Visitor`](crate::visit::Visitor)
+//! for each `Cow` value (borrowed or owned) together with the path to it (e.g. for statistics).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::OwnableVisit;
+//! # use ownable::visit::{FieldPath, Visitor};
+//! #[derive(OwnableVisit)]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   tags: Vec<Cow<'a, str>>,
+//! }
+//!
+//! struct Owned(Vec<String>);
+//!
+//! impl Visitor for Owned {
+//!   fn visit_owned<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, _value: &T::Owned) {
+//!     self.0.push(path.to_string());
+//!   }
+//! }
+//!
+//! let record = Record {
+//!   name: Cow::Borrowed("name"),
+//!   tags: vec![Cow::Borrowed("tag"), Cow::Owned(String::from("owned"))],
+//! };
+//! let mut owned = Owned(Vec::new());
+//! record.visit(&FieldPath::root(), &mut owned);
+//! assert_eq!(owned.0, ["tags[1]"]);
+//! ```
+//!
+//! # Debug
+//!
+//! To find unexpected allocations [`debug::Ownership`](crate::debug::Ownership) shows which `Cow`
+//! values are borrowed (`B`) or owned (`O`) and their size in bytes, and
+//! [`debug::Summary`](crate::debug::Summary) counts them. Both require
+//! [`OwnableVisit`](crate::OwnableVisit).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::OwnableVisit;
+//! # use ownable::debug::{Ownership, Summary};
+//! #[derive(OwnableVisit)]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   tags: Vec<Cow<'a, str>>,
+//! }
+//!
+//! let record = Record {
+//!   name: Cow::Borrowed("name"),
+//!   tags: vec![Cow::Owned(String::from("tag"))],
+//! };
+//! assert_eq!(format!("{:?}", Ownership(&record)), "{name: B(4), tags[0]: O(3)}");
+//! assert_eq!(Summary::of(&record).owned_bytes, 3);
+//! ```
+//!
+//! # Map
+//!
+//! The derive [`MapCows`](crate::MapCows) rebuilds the structure with each `Cow<str>` and
+//! `Cow<[u8]>` passed through a [`CowMapper`](crate::map::CowMapper) (e.g. to normalize parsed
+//! data). Values stay borrowed if the mapper returns (a part of) its input. Other `Cow` values
+//! and the keys of maps are unchanged, but equal mapped items of sets are merged.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::MapCows;
+//! # use ownable::map::str_mapper;
+//! #[derive(MapCows)]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   tags: Vec<Cow<'a, str>>,
+//! }
+//!
+//! let record = Record {
+//!   name: Cow::Borrowed(" name "),
+//!   tags: vec![Cow::Borrowed("Tag")],
+//! };
+//! let record = record.map_cows(&mut str_mapper(|s| {
+//!   let s = s.trim();
+//!   if s.chars().any(char::is_uppercase) {
+//!     Cow::Owned(s.to_lowercase())
+//!   } else {
+//!     Cow::Borrowed(s)
+//!   }
+//! }));
+//! assert_eq!(record.name, Cow::Borrowed("name"));
+//! assert_eq!(record.tags, [Cow::<str>::Owned(String::from("tag"))]);
+//! ```
+//!
 //! # Possible Errors
 //!
 //!  If the following error occurs then one of the fields has a missing trait.
@@ -135,6 +397,24 @@
 //!
 //! # Attributes
 //!
+//! ## bound
+//!
+//! With `#[ownable(bound = "T: Clone")]` the inferred bounds are replaced, at the container all
+//! of them and at a field only the ones of the field. With `#[ownable(bound(into_owned = ".."))]`
+//! (and `to_borrowed`, `to_owned`, .. for all derived functions) they are replaced for a single
+//! mode, an empty string removes them.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::IntoOwned;
+//! #[derive(IntoOwned)]
+//! pub struct Record<'a, T> {
+//!   cow: Cow<'a, str>,
+//!   #[ownable(clone, bound = "T: Clone")]
+//!   value: T,
+//! }
+//! ```
+//!
 //! ## clone
 //!
 //! With `#[ownable(clone)]` and `#[ownable(clone = false|true)]` it's possible to denote that this
@@ -143,15 +423,204 @@
 //!
 //! For an example see the at the top.
 //!
+//! ## crate
+//!
+//! With `#[ownable(crate = "path")]` the generated code refers to the crate with `path` instead of
+//! `::ownable`, e.g. when it's re-exported by another crate or when the derive macros of
+//! `ownable-core` (with the feature `derive`) are used with `#[ownable(crate = "ownable_core")]`.
+//!
+//! ## default
+//!
+//! With `#[ownable(default)]` or `#[ownable(default = "expr")]` at a field it's not converted
+//! but replaced by `Default::default()` or the expression (e.g. for caches or spans into the
+//! input), only `to_borrowed` keeps (clones) the value. The field then only requires `Default`
+//! (and `Clone` for `to_borrowed`) instead of the traits of this crate, and it's not visited by
+//! `for_each_borrow` and `visit`.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use std::ops::Range;
+//! # use ownable::IntoOwned;
+//! #[derive(IntoOwned)]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   #[ownable(default)]
+//!   source: Option<&'a str>,
+//!   #[ownable(default = "0..0")]
+//!   span: Range<usize>,
+//! }
+//! ```
+//!
 //! ## function
 //!
 //! With `#[ownable(function = false)]` at top level (enum/struct) the functions mentioned above
 //! are not implemented, only the [traits](crate::traits).
 //!
+//! The functions can also be configured with a list:
+//! - `vis = "pub(crate)"` sets the visibility of the functions (default: `pub`).
+//! - `into_owned = "into_static"` renames a function (e.g. if it collides with another one) and
+//!   `to_owned = false` disables it (this works for all functions, named as the trait functions,
+//!   for `view` of [`view_type`](#view_type) and for `borrows_from` of
+//!   [`ForEachBorrow`](crate::ForEachBorrow)).
+//! - `alias = "TypeOwned"` defines the type alias `type TypeOwned = Type<'static>` (with the
+//!   visibility of the type), which is the result of `into_owned` and is defined by `IntoOwned`.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::{IntoOwned, ToOwned};
+//! #[derive(IntoOwned, ToOwned)]
+//! #[ownable(function(vis = "pub(crate)", into_owned = "into_static", alias = "RecordOwned"))]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//! }
+//!
+//! fn keep(record: Record<'_>) -> RecordOwned {
+//!   record.into_static()
+//! }
+//! ```
+//!
+//! ## inline
+//!
+//! The generated functions (see [function](#function)) are `#[inline(always)]`, with
+//! `#[ownable(inline = "default")]` at top level (enum/struct) they have no inline attribute and
+//! with `#[ownable(inline = "never")]` they are `#[inline(never)]`, to trade speed for code size.
+//!
+//! ## keep
+//!
+//! With `#[ownable(keep = "..")]` one or more comma separated lifetimes are kept (like the
+//! [reference](#reference) lifetimes), thus only the other lifetimes are released. Fields which
+//! only use kept lifetimes (e.g. `Cow<'arena, str>` or `Vec<Node<'arena>>`) are moved by the
+//! consuming functions (e.g. `into_owned`) and cloned otherwise, which is cheap for borrowed
+//! values.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::IntoOwned;
+//! #[derive(IntoOwned)]
+//! #[ownable(keep = "'arena")]
+//! pub struct Record<'src, 'arena> {
+//!   src: Cow<'src, str>,
+//!   arena: Cow<'arena, str>,
+//! }
+//!
+//! fn release<'arena>(record: Record<'_, 'arena>) -> Record<'static, 'arena> {
+//!   record.into_owned()
+//! }
+//! ```
+//!
+//! ## `owned_type`
+//!
+//! With `#[ownable(owned_type = "TypeOwned")]` at top level (enum/struct) the derive `IntoOwned`
+//! additionally defines the type `TypeOwned`, which is the same without the lifetimes:
+//! a field `Cow<'a, T>` becomes `T::Owned` (e.g. `String` or `Vec<T>`) and all other fields
+//! become their owned version (e.g. the `owned_type` of a nested type).
+//! `to_owned`, `into_owned` and `into_owned_compact` then return `TypeOwned`, and with
+//! [`AsBorrowed`](crate::traits::AsBorrowed) it can be cheaply viewed as the original type.
+//!
+//! Only the `Cow` directly at a field is replaced, nested ones stay, e.g. `Vec<Cow<'a, str>>`
+//! becomes `Vec<Cow<'static, str>>` (with owned values) and not `Vec<String>`.
+//!
+//! Without `IntoOwned` (e.g. only with `ToOwned`) the type is not defined, except with
+//! `#[derive(Ownable)]`, which always defines it (also with `into_owned = false`).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::IntoOwned;
+//! # use ownable::traits::AsBorrowed;
+//! #[derive(Debug, PartialEq, IntoOwned)]
+//! #[ownable(owned_type = "RecordOwned")]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   data: Cow<'a, [u8]>,
+//! }
+//!
+//! let record = Record { name: Cow::Borrowed("name"), data: Cow::Borrowed(b"data") };
+//! let owned: RecordOwned = record.into_owned();
+//! let name: &String = &owned.name;
+//! assert_eq!(owned.as_borrowed(), Record { name: Cow::Borrowed("name"), data: Cow::Borrowed(b"data") });
+//! ```
+//!
+//! ## parallel
+//!
+//! With `#[ownable(parallel)]` at a field of type [`Vec`](::alloc::vec::Vec), `Box<[T]>`,
+//! [`HashMap`](::std::collections::HashMap) or [`BTreeMap`](::alloc::collections::BTreeMap) the
+//! elements are converted in parallel (with `to_owned` and `into_owned`), and with
+//! `#[ownable(parallel = 10000)]` only when there are at least that many elements.
+//!
+//! This requires the feature `rayon`.
+//!
 //! ## reference
 //!
 //! With `#[ownable(reference = "..")]` one or more comma separated lifetimes can be supplied to
-//! be used for references only, see [References](#references) above.
+//! be used for references only, see [References](#references) above. They replace the inferred
+//! ones and with `#[ownable(reference = "")]` none are used.
+//!
+//! ## skip
+//!
+//! `#[ownable(skip)]` is the same as `default` (and can be combined with it), but the value is
+//! also replaced by `to_borrowed`.
+//!
+//! ## `stack_safe`
+//!
+//! With `#[ownable(stack_safe)]` at top level (enum/struct) each conversion runs within
+//! [`stack::guard`](crate::stack::guard), which grows the stack on the heap when required.
+//! This allows to convert deeply nested and recursive types (e.g. a linked list).
+//! With [`stack::with_max_depth`](crate::stack::with_max_depth) the nesting can also be limited.
+//!
+//! This requires the feature `stack_safe`.
+//!
+//! ## `view_type`
+//!
+//! With `#[ownable(view_type = "TypeRef")]` at top level (enum/struct) the derive `ToBorrowed`
+//! additionally defines the type `TypeRef<'o>`, where each field references the original value:
+//! `String` becomes `&'o str`, `Vec<T>` becomes `&'o [T]`, `Box<T>` and `Cow<'a, T>` become
+//! `&'o T` and all others `U` become `&'o U` (references are copied, but mutable references are
+//! not supported). The types are recognized by their name or their full path (e.g.
+//! `std::vec::Vec`), a type `my::Vec` is referenced as a whole. Unlike `to_borrowed` the
+//! function `view` (and [`AsBorrowed`](crate::traits::AsBorrowed)) never allocates, not even for
+//! fields with `clone`. The function is configured like the others (see [function](#function)).
+//! Like with `owned_type` the `#[derive(Ownable)]` always defines it (also with
+//! `to_borrowed = false`).
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::ToBorrowed;
+//! #[derive(ToBorrowed)]
+//! #[ownable(view_type = "RecordRef")]
+//! pub struct Record<'a> {
+//!   name: Cow<'a, str>,
+//!   #[ownable(clone)]
+//!   description: String,
+//! }
+//!
+//! let record = Record { name: Cow::Borrowed("name"), description: String::from("text") };
+//! let view: RecordRef<'_> = record.view();
+//! assert_eq!((view.name, view.description), ("name", "text"));
+//! ```
+//!
+//! ## with
+//!
+//! With `#[ownable(with = "module")]` at a field its conversion is done by the functions of the
+//! module, which are named like the derived functions (e.g. `module::into_owned`) and have the
+//! same parameters (e.g. `fn to_owned(value: &External<'_>) -> External<'static>`).
+//! This allows to convert third party types which already have their own conversion.
+//!
+//! With `#[ownable(into_owned_with = "path")]` (and `to_borrowed_with`, `to_owned_with`, .. for
+//! all derived functions) a single conversion can be set or overwritten.
+//!
+//! ```rust
+//! # use std::borrow::Cow;
+//! # use ownable::IntoOwned;
+//! # pub struct External<'a>(Cow<'a, str>);
+//! # impl External<'_> {
+//! #   fn into_owned(self) -> External<'static> { External(Cow::Owned(self.0.into_owned())) }
+//! # }
+//! #[derive(IntoOwned)]
+//! pub struct Record<'a> {
+//!   #[ownable(into_owned_with = "External::into_owned")]
+//!   external: External<'a>,
+//! }
+//! ```
 //!
 //! # AsCopy/AsClone
 //!
@@ -180,6 +649,9 @@
 //!
 //! # Features
 //! * `std` - Traits are also implemented for types which are not in [core](::core) or [alloc](::alloc) (currently [`HashMap`](::std::collections::HashMap) and [`HashSet`](::std::collections::HashSet)).
+//! * `rayon` - Adds the traits [`ParToOwned`](crate::traits::ParToOwned) and [`ParIntoOwned`](crate::traits::ParIntoOwned) which convert large collections in parallel (implies `std`).
+//! * `stack_safe` - Adds the module [`stack`](crate::stack) for the conversion of deeply nested types (implies `std`).
+//! * `no_inline_always` - The trivial impls (e.g. for [`Copy`] types and references) are only `#[inline]` instead of `#[inline(always)]`, this trades speed for code size (see also [inline](#inline) for the derived code).
 //!
 //! `std` is enabled by default.
 //!
@@ -187,18 +659,26 @@
 //! With defaults (includes `std`):
 //! ```toml
 //! [dependencies]
-//! ownable = "1.0"
+//! ownable = "2.0"
 //! ```
 //!
 //! With `no_std` (but still requires alloc):
 //! ```toml
 //! [dependencies]
-//! ownable = { version = "1.0", default-features = false }
+//! ownable = { version = "2.0", default-features = false }
 //! ```
 
 extern crate alloc;
 
-pub use ownable_core::{AsClone, AsCopy};
-pub use ownable_macro::{IntoOwned, ToBorrowed, ToOwned};
+#[doc(hidden)]
+pub use ownable_core::__private;
+pub use ownable_core::{debug, map, visit};
+pub use ownable_core::{AsClone, AsCopy, Leaker};
+pub use ownable_macro::{
+    ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, MapCows, Ownable, OwnableVisit, Rebase,
+    ToBorrowed, ToOwned,
+};
 
+#[cfg(feature = "stack_safe")]
+pub use ownable_core::stack;
 pub mod traits;
@@ -8,4 +8,9 @@
 //! If you don't rely on the derive macros then you may benefit from pulling in fewer dependencies
 //! by relying directly on [ownable-core](https://docs.rs/ownable-core).
 
-pub use ownable_core::{IntoOwned, ToBorrowed, ToOwned};
+pub use ownable_core::{
+    AsBorrowed, ForEachBorrow, IntoLeaked, IntoOwned, IntoOwnedCompact, Leakable, MapCows,
+    OwnableVisit, Rebase, ToBorrowed, ToOwned,
+};
+#[cfg(feature = "rayon")]
+pub use ownable_core::{ParIntoOwned, ParToOwned};
@@ -0,0 +1,73 @@
+use ownable::{ForEachBorrow, IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+fn into_owned_identity<T>(value: T) -> T {
+    value
+}
+
+// The bounds of `T` are inferred (and `U` is only used as marker).
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned, ForEachBorrow)]
+struct Test<'a, T, U> {
+    cow: Cow<'a, str>,
+    value: T,
+    list: Vec<T>,
+    marker: PhantomData<U>,
+}
+
+// The bounds are set for the fields and replaced for the container in `to_owned`.
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+#[ownable(bound(to_owned = "T: Clone, U: Clone"))]
+enum TestEnum<'a, T, U> {
+    Named {
+        cow: Cow<'a, str>,
+        #[ownable(clone, bound = "T: Clone")]
+        value: T,
+    },
+    Unnamed(
+        #[ownable(
+            into_owned_with = "into_owned_identity",
+            bound(into_owned = ""),
+            to_owned_with = "Clone::clone"
+        )]
+        U,
+    ),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<'_, u32, String> = Test {
+        cow: Cow::Borrowed(&value),
+        value: 1,
+        list: vec![2, 3],
+        marker: PhantomData,
+    };
+    let v1: Test<'_, u32, String> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let mut count = 0;
+    v1.for_each_borrow(&mut |_| count += 1);
+    assert_eq!(count, 1);
+    let v2: Test<'static, u32, String> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: Test<'static, u32, String> = v0.into_owned();
+    assert_eq!(v2, v3);
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<'_, String, Vec<u8>> = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+        value: value.clone(),
+    };
+    let v1: TestEnum<'_, String, Vec<u8>> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: TestEnum<'static, String, Vec<u8>> = v0.into_owned();
+    assert!(matches!(&v2, TestEnum::Named { cow: Cow::Owned(c), value: v } if c == v));
+    let v3: TestEnum<'_, String, Vec<u8>> = TestEnum::Unnamed(vec![1, 2]);
+    let v4: TestEnum<'static, String, Vec<u8>> = v3.to_owned();
+    assert_eq!(v3, v4);
+    let v5: TestEnum<'static, String, Vec<u8>> = v3.into_owned();
+    assert_eq!(v4, v5);
+}
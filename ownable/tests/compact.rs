@@ -0,0 +1,97 @@
+use ownable::{IntoOwned, IntoOwnedCompact};
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+use std::collections::VecDeque;
+
+#[derive(Debug, PartialEq, IntoOwned, IntoOwnedCompact)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    vec: Vec<Cow<'a, str>>,
+    deque: VecDeque<u32>,
+    #[cfg(feature = "std")]
+    map: HashMap<u32, Cow<'a, str>>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, IntoOwnedCompact)]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Vec<u8>),
+}
+
+fn with_capacity(value: &str) -> String {
+    let mut s = String::with_capacity(1000);
+    s.push_str(value);
+    s
+}
+
+#[test]
+fn test() {
+    let mut vec = Vec::with_capacity(1000);
+    vec.push(Cow::Owned(with_capacity("vec")));
+    let mut deque = VecDeque::with_capacity(1000);
+    deque.push_back(1);
+    #[cfg(feature = "std")]
+    let mut map = HashMap::with_capacity(1000);
+    #[cfg(feature = "std")]
+    map.insert(1, Cow::Owned(with_capacity("map")));
+    let v0: Test<'_> = Test {
+        cow: Cow::Owned(with_capacity("cow")),
+        bytes: Cow::Owned(Vec::with_capacity(1000)),
+        vec,
+        deque,
+        #[cfg(feature = "std")]
+        map,
+    };
+    let v1: Test<'static> = v0.into_owned_compact();
+    assert_eq!(v1.cow.as_ref(), "cow");
+    assert!(matches!(&v1.cow, Cow::Owned(s) if s.capacity() == 3));
+    assert!(matches!(&v1.bytes, Cow::Owned(b) if b.capacity() == 0));
+    assert_eq!(v1.vec.capacity(), 1);
+    assert!(matches!(&v1.vec[0], Cow::Owned(s) if s.capacity() == 3));
+    assert!(v1.deque.capacity() < 1000);
+    #[cfg(feature = "std")]
+    {
+        assert!(v1.map.capacity() < 1000);
+        assert!(matches!(&v1.map[&1], Cow::Owned(s) if s.capacity() == 3));
+    }
+}
+
+#[test]
+fn test_enum() {
+    let v0: TestEnum<'_> = TestEnum::Named {
+        cow: Cow::Owned(with_capacity("cow")),
+    };
+    let v1: TestEnum<'static> = v0.into_owned_compact();
+    assert!(matches!(&v1, TestEnum::Named { cow: Cow::Owned(s) } if s.capacity() == 3));
+    let mut vec = Vec::with_capacity(1000);
+    vec.push(0);
+    let v2: TestEnum<'static> = TestEnum::Unnamed(vec).into_owned_compact();
+    assert!(matches!(&v2, TestEnum::Unnamed(v) if v.capacity() == 1));
+}
+
+#[derive(Debug, PartialEq, IntoOwned, IntoOwnedCompact)]
+struct TestOther<'a> {
+    numbers: Cow<'a, [u32]>,
+    sized: Cow<'a, u32>,
+    #[cfg(feature = "std")]
+    c_str: Cow<'a, std::ffi::CStr>,
+}
+
+#[test]
+fn test_other() {
+    let mut numbers = Vec::with_capacity(1000);
+    numbers.push(1);
+    let v0: TestOther<'_> = TestOther {
+        numbers: Cow::Owned(numbers),
+        sized: Cow::Borrowed(&2),
+        #[cfg(feature = "std")]
+        c_str: Cow::Borrowed(std::ffi::CStr::from_bytes_with_nul(b"c_str\0").unwrap()),
+    };
+    let v1: TestOther<'static> = v0.into_owned_compact();
+    assert!(matches!(&v1.numbers, Cow::Owned(v) if v.capacity() == 1));
+    assert_eq!(v1.sized, Cow::Owned::<u32>(2));
+    #[cfg(feature = "std")]
+    assert!(matches!(&v1.c_str, Cow::Owned(s) if s.as_bytes() == b"c_str"));
+}
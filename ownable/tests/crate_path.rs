@@ -0,0 +1,51 @@
+use std::borrow::Cow;
+
+// A facade crate, which re-exports ownable.
+mod facade {
+    pub use ownable::*;
+}
+
+#[derive(Debug, PartialEq, facade::IntoOwned, facade::ToBorrowed, facade::OwnableVisit)]
+#[ownable(crate = "crate::facade", owned_type = "TestOwned")]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    list: Vec<Cow<'a, [u8]>>,
+}
+
+// The traits are used directly from `ownable-core`.
+#[derive(Debug, PartialEq, ownable::IntoOwned, ownable::ToOwned, ownable::IntoLeaked)]
+#[ownable(crate = "ownable_core")]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, str>),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0 = Test {
+        cow: Cow::Borrowed(&value),
+        list: vec![Cow::Borrowed(b"list")],
+    };
+    let v1: Test<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: TestOwned = v1.into_owned();
+    assert_eq!(v2.cow, "value");
+    // the nested `Cow` stays (with an owned value)
+    assert!(matches!(&v2.list[..], [Cow::Owned(l)] if l == b"list"));
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0 = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+    };
+    let v1: TestEnum<'static> = v0.to_owned();
+    assert_eq!(v0, v1);
+    let v2: TestEnum<'static> = TestEnum::Unnamed(Cow::Borrowed(&value)).into_owned();
+    assert_eq!(v2, TestEnum::Unnamed(Cow::Borrowed("value")));
+    let mut leaker = ownable::Leaker::new();
+    let v3: TestEnum<'static> = v0.into_leaked(&mut leaker);
+    assert_eq!(v1, v3);
+}
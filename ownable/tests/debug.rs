@@ -0,0 +1,60 @@
+use ownable::debug::{Ownership, Summary};
+use ownable::OwnableVisit;
+use std::borrow::Cow;
+
+#[derive(Debug, PartialEq, OwnableVisit)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    nested: Option<Box<Test<'a>>>,
+    #[ownable(clone)]
+    cloned: String,
+}
+
+#[derive(Debug, PartialEq, OwnableVisit)]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, str>),
+    Unit,
+}
+
+#[test]
+fn test() {
+    let input: String = "some input".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&input),
+        bytes: Cow::Owned(vec![0; 3]),
+        nested: Some(Box::new(Test {
+            cow: Cow::Owned("owned".to_string()),
+            bytes: Cow::Borrowed(&input.as_bytes()[..4]),
+            nested: None,
+            cloned: "cloned".to_string(),
+        })),
+        cloned: "cloned".to_string(),
+    };
+    assert_eq!(
+        format!("{:?}", Ownership(&v0)),
+        "{cow: B(10), bytes: O(3), nested.cow: O(5), nested.bytes: B(4)}"
+    );
+    assert_eq!(
+        Summary::of(&v0),
+        Summary {
+            borrowed: 2,
+            borrowed_bytes: 14,
+            owned: 2,
+            owned_bytes: 8,
+        }
+    );
+}
+
+#[test]
+fn test_enum() {
+    let v0 = TestEnum::Named {
+        cow: Cow::Borrowed("input"),
+    };
+    assert_eq!(format!("{:?}", Ownership(&v0)), "{Named.cow: B(5)}");
+    let v1 = TestEnum::Unnamed(Cow::Owned("input".to_string()));
+    assert_eq!(format!("{:?}", Ownership(&v1)), "{Unnamed.0: O(5)}");
+    assert_eq!(format!("{:?}", Ownership(&TestEnum::Unit)), "{}");
+    assert_eq!(Summary::of(&TestEnum::Unit), Summary::default());
+}
@@ -0,0 +1,83 @@
+use ownable::ForEachBorrow;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, ForEachBorrow)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    nested: Option<Box<Test<'a>>>,
+    map: BTreeMap<u32, Cow<'a, [u8]>>,
+}
+
+#[derive(Debug, PartialEq, ForEachBorrow)]
+#[ownable(reference = "'r")]
+enum TestEnum<'a, 'r> {
+    Named {
+        cow: Cow<'a, str>,
+        reference: &'r str,
+    },
+    Unnamed(Cow<'a, str>, #[ownable(clone)] String),
+    Unit,
+}
+
+#[derive(Debug, PartialEq, ForEachBorrow)]
+struct TestUnit;
+
+// `borrows_from` is configured like the other functions.
+#[derive(Debug, PartialEq, ForEachBorrow)]
+#[ownable(function(for_each_borrow = false, borrows_from = "within"))]
+struct TestFunction<'a>(Cow<'a, str>);
+
+impl TestFunction<'_> {
+    fn borrows_from(&self) -> bool {
+        false
+    }
+}
+
+#[test]
+fn test() {
+    let input: String = "some input".to_string();
+    let other: String = "other".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&input[5..]),
+        nested: Some(Box::new(Test {
+            cow: Cow::Owned("owned".to_string()),
+            nested: None,
+            map: BTreeMap::from([(1, Cow::Borrowed(input.as_bytes()))]),
+        })),
+        map: BTreeMap::new(),
+    };
+    let mut ranges = Vec::new();
+    v0.for_each_borrow(&mut |range| ranges.push(range));
+    let start = input.as_ptr() as usize;
+    assert_eq!(ranges, vec![start + 5..start + 10, start..start + 10]);
+    assert!(v0.borrows_from(input.as_bytes()));
+    assert!(!v0.borrows_from(&input.as_bytes()[5..]));
+    assert!(!v0.borrows_from(other.as_bytes()));
+}
+
+#[test]
+fn test_enum() {
+    let input: String = "some input".to_string();
+    let v0: TestEnum<'_, '_> = TestEnum::Named {
+        cow: Cow::Borrowed(&input),
+        reference: "reference",
+    };
+    assert!(v0.borrows_from(input.as_bytes()));
+    let v1: TestEnum<'_, '_> = TestEnum::Unnamed(Cow::Owned("owned".to_string()), String::new());
+    assert!(v1.borrows_from(&[]));
+    assert!(TestEnum::Unit.borrows_from(&[]));
+    assert!(TestUnit.borrows_from(&[]));
+}
+
+#[test]
+fn test_function() {
+    let input: String = "input".to_string();
+    let v0: TestFunction<'_> = TestFunction(Cow::Borrowed(&input));
+    assert!(v0.within(input.as_bytes()));
+    assert!(!v0.borrows_from());
+    assert!(ownable::traits::ForEachBorrow::borrows_from(
+        &v0,
+        input.as_bytes()
+    ));
+}
@@ -7,6 +7,27 @@ struct Test<'a> {
     cow: Cow<'a, str>,
 }
 
+// The functions are renamed, disabled (e.g. for an own function) and restricted.
+#[derive(Debug, PartialEq, IntoOwned, ToOwned, ToBorrowed)]
+#[ownable(function(
+    vis = "pub(crate)",
+    into_owned = "into_static",
+    to_owned = false,
+    alias = "TestEnumOwned"
+))]
+pub enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, str>),
+}
+
+impl TestEnum<'_> {
+    fn to_owned(&self) -> String {
+        match self {
+            TestEnum::Named { cow } | TestEnum::Unnamed(cow) => cow.to_string(),
+        }
+    }
+}
+
 #[test]
 fn test() {
     // this would not be used when the functions are generated
@@ -24,3 +45,18 @@ fn test() {
     let v3: Test<'static> = v0.into_owned();
     assert_eq!(v2, v3);
 }
+
+#[test]
+fn test_enum() {
+    let value: String = "value".to_string();
+    let v0: TestEnum<'_> = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+    };
+    let v1: TestEnum<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    assert_eq!(v0.to_owned(), value);
+    let v2: TestEnumOwned = ownable::traits::ToOwned::to_owned(&v0);
+    assert_eq!(v0, v2);
+    let v3: TestEnumOwned = v0.into_static();
+    assert_eq!(v2, v3);
+}
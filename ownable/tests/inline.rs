@@ -0,0 +1,47 @@
+use ownable::{ForEachBorrow, IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned, ForEachBorrow)]
+#[ownable(inline = "never")]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+#[ownable(inline = "default")]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, str>),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&value),
+    };
+    let v1: Test<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    assert!(v1.borrows_from(value.as_bytes()));
+    let v2: Test<'static> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: Test<'static> = v0.into_owned();
+    assert_eq!(v2, v3);
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<'_> = TestEnum::Unnamed(Cow::Borrowed(&value));
+    let v1: TestEnum<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: TestEnum<'static> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: TestEnum<'static> = v0.into_owned();
+    assert_eq!(v2, v3);
+    let v4: TestEnum<'static> = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+    }
+    .into_owned();
+    assert!(matches!(v4, TestEnum::Named { cow: Cow::Owned(c) } if c == value));
+}
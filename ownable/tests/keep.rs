@@ -0,0 +1,85 @@
+use ownable::{IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+#[derive(Debug, PartialEq, Clone, ToBorrowed, ToOwned, IntoOwned)]
+struct Arena<'arena> {
+    name: Cow<'arena, str>,
+}
+
+// Only the lifetime `'src` is released, the values of `'arena` are passed through.
+#[derive(Debug, PartialEq, ToBorrowed, ToOwned, IntoOwned)]
+#[ownable(keep = "'arena")]
+struct Test<'src, 'arena> {
+    src: Cow<'src, str>,
+    arena: Cow<'arena, str>,
+    nested: Arena<'arena>,
+    list: Vec<Cow<'arena, str>>,
+}
+
+#[derive(Debug, PartialEq, ToBorrowed, ToOwned, IntoOwned)]
+#[ownable(keep = "'arena")]
+enum TestEnum<'src, 'arena> {
+    Named { test: Test<'src, 'arena> },
+    Unnamed(Cow<'src, str>, Cow<'arena, str>),
+}
+
+macro_rules! is_borrowed {
+    ($cow:expr) => {
+        matches!($cow, Cow::Borrowed(_))
+    };
+}
+
+#[test]
+fn test() {
+    let arena = "arena".to_string();
+    let v0: Test<'_, '_> = {
+        let src = "src".to_string();
+        let v0 = Test {
+            src: Cow::Borrowed(&src),
+            arena: Cow::Borrowed(&arena),
+            nested: Arena {
+                name: Cow::Borrowed(&arena),
+            },
+            list: vec![Cow::Borrowed(&arena)],
+        };
+        let v1: Test<'_, '_> = v0.to_borrowed();
+        assert_eq!(v0, v1);
+        let v2: Test<'static, '_> = v0.to_owned();
+        assert_eq!(v0, v2);
+        assert!(is_borrowed!(v2.arena) && is_borrowed!(v2.nested.name));
+        v0.into_owned()
+    };
+    assert!(!is_borrowed!(v0.src));
+    assert!(is_borrowed!(v0.arena) && is_borrowed!(v0.nested.name) && is_borrowed!(v0.list[0]));
+}
+
+#[test]
+fn test_enum() {
+    let arena = "arena".to_string();
+    let v0: TestEnum<'static, '_> = {
+        let src = "src".to_string();
+        let v0 = TestEnum::Unnamed(Cow::Borrowed(&src), Cow::Borrowed(&arena));
+        let v1: TestEnum<'_, '_> = v0.to_borrowed();
+        assert_eq!(v0, v1);
+        let v2: TestEnum<'static, '_> = v0.to_owned();
+        assert_eq!(v0, v2);
+        v0.into_owned()
+    };
+    assert!(matches!(
+        &v0,
+        TestEnum::Unnamed(Cow::Owned(_), Cow::Borrowed(_))
+    ));
+    let v1: TestEnum<'static, '_> = {
+        let src = "src".to_string();
+        let test = Test {
+            src: Cow::Borrowed(&src),
+            arena: Cow::Borrowed(&arena),
+            nested: Arena {
+                name: Cow::Borrowed(&arena),
+            },
+            list: Vec::new(),
+        };
+        TestEnum::Named { test }.into_owned()
+    };
+    assert!(matches!(&v1, TestEnum::Named { test } if is_borrowed!(test.arena)));
+}
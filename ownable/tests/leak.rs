@@ -0,0 +1,145 @@
+use ownable::{IntoLeaked, Leaker};
+use std::borrow::Cow;
+
+#[derive(Debug, PartialEq, IntoLeaked)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    nested: Option<Box<Test<'a>>>,
+    list: Vec<Cow<'a, str>>,
+}
+
+#[derive(Debug, PartialEq, IntoLeaked)]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, str>, u8),
+}
+
+// Distinct types, which are leaked with the same leaker.
+#[derive(Debug, PartialEq, IntoLeaked)]
+struct Outer<'a> {
+    name: Cow<'a, str>,
+    inner: Inner<'a>,
+    tagged: TestEnum<'a>,
+}
+
+#[derive(Debug, PartialEq, IntoLeaked)]
+struct Inner<'a> {
+    names: Vec<Cow<'a, str>>,
+}
+
+#[test]
+fn test() {
+    let value: String = "value".to_string();
+    let leaked: Test<'static> = Test {
+        cow: Cow::Borrowed(&value),
+        bytes: Cow::Owned(b"bytes".to_vec()),
+        nested: None,
+        list: vec![],
+    }
+    .into_leaked(&mut Leaker::new());
+    assert!(matches!(leaked.cow, Cow::Borrowed("value")));
+    assert!(matches!(leaked.bytes, Cow::Borrowed(b"bytes")));
+
+    let interned: Test<'static> = Test {
+        cow: Cow::Borrowed(&value),
+        bytes: Cow::Owned(b"bytes".to_vec()),
+        nested: Some(Box::new(Test {
+            cow: Cow::Owned("value".to_string()),
+            bytes: Cow::Borrowed(b"bytes"),
+            nested: None,
+            list: vec![],
+        })),
+        list: vec![Cow::Borrowed(&value)],
+    }
+    .into_leaked(&mut Leaker::interning());
+    let nested = interned.nested.as_ref().unwrap();
+    assert!(matches!(nested.cow, Cow::Borrowed("value")));
+    assert!(std::ptr::eq(interned.cow.as_ref(), nested.cow.as_ref()));
+    assert!(std::ptr::eq(
+        interned.cow.as_ref(),
+        interned.list[0].as_ref()
+    ));
+    // only strings are interned
+    assert!(!std::ptr::eq(
+        interned.bytes.as_ref(),
+        nested.bytes.as_ref()
+    ));
+    // a clone of a leaked value is just a copy of the reference
+    assert!(matches!(interned.cow.clone(), Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_enum() {
+    let value: String = "value".to_string();
+    let mut leaker = Leaker::new();
+    let named: TestEnum<'static> = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+    }
+    .into_leaked(&mut leaker);
+    assert!(matches!(
+        named,
+        TestEnum::Named {
+            cow: Cow::Borrowed("value")
+        }
+    ));
+    let unnamed: TestEnum<'static> =
+        TestEnum::Unnamed(Cow::Borrowed(&value), 1).into_leaked(&mut leaker);
+    assert!(matches!(
+        unnamed,
+        TestEnum::Unnamed(Cow::Borrowed("value"), 1)
+    ));
+}
+
+#[test]
+fn test_interning() {
+    let mut leaker = Leaker::interning();
+    let outer: Outer<'static> = Outer {
+        name: Cow::Owned("name".to_string()),
+        inner: Inner {
+            names: vec![Cow::Borrowed("other"), Cow::Owned("name".to_string())],
+        },
+        tagged: TestEnum::Unnamed(Cow::Owned("other".to_string()), 0),
+    }
+    .into_leaked(&mut leaker);
+    // the strings are shared across the types and with later calls using the same leaker
+    assert!(std::ptr::eq(
+        outer.name.as_ref(),
+        outer.inner.names[1].as_ref()
+    ));
+    assert!(matches!(
+        &outer.tagged,
+        TestEnum::Unnamed(other, 0) if std::ptr::eq(outer.inner.names[0].as_ref(), other.as_ref())
+    ));
+    let inner: Inner<'static> = Inner {
+        names: vec![Cow::Borrowed("name")],
+    }
+    .into_leaked(&mut leaker);
+    assert!(std::ptr::eq(outer.name.as_ref(), inner.names[0].as_ref()));
+    // but not between leakers
+    let inner: Inner<'static> = Inner {
+        names: vec![Cow::Borrowed("name")],
+    }
+    .into_leaked(&mut Leaker::interning());
+    assert!(!std::ptr::eq(outer.name.as_ref(), inner.names[0].as_ref()));
+}
+
+#[derive(Debug, PartialEq, IntoLeaked)]
+enum TestNames<'a> {
+    Named { leaker: Cow<'a, str> },
+}
+
+#[test]
+fn test_names() {
+    let value: String = "value".to_string();
+    let v0: TestNames<'static> = TestNames::Named {
+        leaker: Cow::Borrowed(&value),
+    }
+    .into_leaked(&mut Leaker::new());
+    assert!(matches!(
+        v0,
+        TestNames::Named {
+            leaker: Cow::Borrowed("value")
+        }
+    ));
+}
@@ -0,0 +1,148 @@
+use ownable::map::{bytes_mapper, str_mapper, CowMapper};
+use ownable::MapCows;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+#[cfg(feature = "std")]
+use std::path::Path;
+
+#[derive(Debug, PartialEq, MapCows)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    nested: Option<Box<Test<'a>>>,
+    map: BTreeMap<u32, Cow<'a, str>>,
+    #[ownable(clone)]
+    cloned: String,
+}
+
+// Other `Cow` values and the keys of maps are unchanged, equal items of sets are merged.
+#[derive(Debug, PartialEq, MapCows)]
+struct TestOther<'a> {
+    numbers: Cow<'a, [u32]>,
+    keys: BTreeMap<Cow<'a, str>, Cow<'a, str>>,
+    set: BTreeSet<Cow<'a, str>>,
+    #[cfg(feature = "std")]
+    path: Cow<'a, Path>,
+}
+
+#[derive(Debug, PartialEq, MapCows)]
+#[ownable(reference = "'r")]
+enum TestEnum<'a, 'r> {
+    Named {
+        cow: Cow<'a, str>,
+        reference: &'r str,
+    },
+    Unnamed(Cow<'a, str>, #[ownable(clone)] String),
+    Unit,
+}
+
+#[derive(Debug, PartialEq, MapCows)]
+struct TestUnit;
+
+struct Upper;
+
+impl CowMapper for Upper {
+    fn map_str<'c>(&mut self, value: &'c str) -> Cow<'c, str> {
+        Cow::Owned(value.to_uppercase())
+    }
+
+    fn map_bytes<'c>(&mut self, value: &'c [u8]) -> Cow<'c, [u8]> {
+        Cow::Owned(value.to_ascii_uppercase())
+    }
+}
+
+#[test]
+fn test() {
+    let input: String = " some input ".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&input),
+        bytes: Cow::Borrowed(input.as_bytes()),
+        nested: Some(Box::new(Test {
+            cow: Cow::Owned("owned".to_string()),
+            bytes: Cow::Owned(b" owned ".to_vec()),
+            nested: None,
+            map: BTreeMap::from([(1, Cow::Owned(" x ".to_string()))]),
+            cloned: " cloned ".to_string(),
+        })),
+        map: BTreeMap::new(),
+        cloned: " cloned ".to_string(),
+    };
+    let owned_ptr = v0.nested.as_ref().unwrap().cow.as_ptr();
+    let trimmed = v0.map_cows(&mut str_mapper(|s| Cow::Borrowed(s.trim())));
+    assert!(matches!(trimmed.cow, Cow::Borrowed("some input")));
+    assert_eq!(trimmed.bytes, input.as_bytes());
+    let nested = trimmed.nested.as_ref().unwrap();
+    // unchanged owned values keep their allocation
+    assert!(matches!(nested.cow, Cow::Owned(_)));
+    assert_eq!(nested.cow.as_ptr(), owned_ptr);
+    assert_eq!(nested.map[&1], "x");
+    assert_eq!(trimmed.cloned, " cloned ");
+
+    let inner = trimmed.map_cows(&mut bytes_mapper(|b| Cow::Borrowed(&b[1..b.len() - 1])));
+    assert_eq!(&*inner.bytes, b"some input");
+    assert_eq!(&*inner.nested.as_ref().unwrap().bytes, b"owned");
+    assert_eq!(inner.cow, "some input");
+
+    let upper = inner.map_cows(&mut Upper);
+    assert_eq!(upper.cow, "SOME INPUT");
+    assert_eq!(&*upper.bytes, b"SOME INPUT");
+    assert_eq!(upper.nested.unwrap().map[&1], "X");
+}
+
+#[test]
+fn test_owned_equal() {
+    let input: String = "input".to_string();
+    let owned: String = "owned".to_string();
+    let owned_ptr = owned.as_ptr();
+    let value: Vec<Cow<'_, str>> = vec![Cow::Borrowed(&input), Cow::Owned(owned)];
+    // an equal but newly allocated result replaces the value
+    let mapped =
+        ownable::traits::MapCows::map_cows(value, &mut str_mapper(|s| Cow::Owned(s.to_string())));
+    assert!(matches!(&mapped[0], Cow::Owned(s) if s == "input"));
+    assert!(matches!(&mapped[1], Cow::Owned(s) if s == "owned"));
+    assert_ne!(mapped[1].as_ptr(), owned_ptr);
+}
+
+#[test]
+fn test_other() {
+    let v0 = TestOther {
+        numbers: Cow::Borrowed(&[1, 2]),
+        keys: BTreeMap::from([
+            (Cow::Borrowed("a"), Cow::Borrowed("a")),
+            (Cow::Borrowed("A"), Cow::Borrowed("b")),
+        ]),
+        set: BTreeSet::from([Cow::Borrowed("a"), Cow::Borrowed("A")]),
+        #[cfg(feature = "std")]
+        path: Cow::Borrowed(Path::new("path")),
+    };
+    let v1 = v0.map_cows(&mut Upper);
+    assert!(matches!(v1.numbers, Cow::Borrowed(&[1, 2])));
+    assert_eq!(v1.keys.len(), 2);
+    assert_eq!(v1.keys["a"], "A");
+    assert_eq!(v1.keys["A"], "B");
+    assert_eq!(v1.set, BTreeSet::from([Cow::Borrowed("A")]));
+    #[cfg(feature = "std")]
+    assert_eq!(v1.path, Path::new("path"));
+}
+
+#[test]
+fn test_enum() {
+    let v0: TestEnum<'_, '_> = TestEnum::Named {
+        cow: Cow::Borrowed("input"),
+        reference: "reference",
+    };
+    assert_eq!(
+        v0.map_cows(&mut Upper),
+        TestEnum::Named {
+            cow: Cow::Owned("INPUT".to_string()),
+            reference: "reference",
+        }
+    );
+    let v1: TestEnum<'_, '_> = TestEnum::Unnamed(Cow::Borrowed("input"), "clone".to_string());
+    assert_eq!(
+        v1.map_cows(&mut Upper),
+        TestEnum::Unnamed(Cow::Borrowed("INPUT"), "clone".to_string())
+    );
+    assert_eq!(TestEnum::Unit.map_cows(&mut Upper), TestEnum::Unit);
+    assert_eq!(TestUnit.map_cows(&mut Upper), TestUnit);
+}
@@ -0,0 +1,43 @@
+use ownable::ToBorrowed;
+use std::borrow::Cow;
+
+// A generic container without lifetimes, which is used with `Cow`.
+#[derive(Debug, PartialEq, ToBorrowed)]
+struct Test<T> {
+    value: T,
+    children: Vec<Test<T>>,
+}
+
+#[derive(Debug, PartialEq, ToBorrowed)]
+enum TestEnum<T> {
+    Named { value: T },
+    Unnamed(Option<T>, u32),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<Cow<'_, str>> = Test {
+        value: Cow::Borrowed(&value),
+        children: vec![Test {
+            value: Cow::Owned("child".to_string()),
+            children: Vec::new(),
+        }],
+    };
+    let v1: Test<Cow<'_, str>> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    assert!(matches!(v1.children[0].value, Cow::Borrowed(_)));
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<Cow<'_, str>> = TestEnum::Named {
+        value: Cow::Owned(value.clone()),
+    };
+    let v1: TestEnum<Cow<'_, str>> = v0.to_borrowed();
+    assert!(matches!(&v1, TestEnum::Named { value: Cow::Borrowed(v) } if *v == value));
+    let v2: TestEnum<u32> = TestEnum::Unnamed(Some(1), 2);
+    let v3: TestEnum<u32> = v2.to_borrowed();
+    assert_eq!(v2, v3);
+}
@@ -0,0 +1,56 @@
+use ownable::Ownable;
+use std::borrow::Cow;
+
+#[derive(Debug, PartialEq, Ownable)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    #[ownable(clone)]
+    owned: String,
+}
+
+// The derive of `to_owned` is disabled, thus it can be implemented by hand.
+#[derive(Debug, PartialEq, Ownable)]
+#[ownable(to_owned = false)]
+enum TestEnum<'a> {
+    Named { cow: Cow<'a, str> },
+    Unnamed(Cow<'a, str>),
+}
+
+impl ownable::traits::ToOwned for TestEnum<'_> {
+    type Owned = TestEnum<'static>;
+
+    fn to_owned(&self) -> Self::Owned {
+        self.to_borrowed().into_owned()
+    }
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&value),
+        owned: value.clone(),
+    };
+    let v1: Test<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: Test<'static> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: Test<'static> = v0.into_owned();
+    assert_eq!(v2, v3);
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<'_> = TestEnum::Named {
+        cow: Cow::Borrowed(&value),
+    };
+    let v1: TestEnum<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: TestEnum<'static> = ownable::traits::ToOwned::to_owned(&v0);
+    assert_eq!(v0, v2);
+    let v3: TestEnum<'static> = v0.into_owned();
+    assert_eq!(v2, v3);
+    let v4: TestEnum<'static> = TestEnum::Unnamed(Cow::Borrowed(&value)).into_owned();
+    assert!(matches!(v4, TestEnum::Unnamed(Cow::Owned(c)) if c == value));
+}
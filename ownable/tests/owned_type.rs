@@ -0,0 +1,144 @@
+use ownable::traits::AsBorrowed;
+use ownable::{IntoOwned, IntoOwnedCompact, Ownable, ToOwned};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, IntoOwned, ToOwned)]
+#[ownable(owned_type = "TestOwned")]
+pub struct Test<'a> {
+    pub cow: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    nested: Option<Box<Test<'a>>>,
+    inner: Inner<'a>,
+    map: BTreeMap<u32, Cow<'a, str>>,
+    #[ownable(clone)]
+    cloned: String,
+    copy: usize,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, IntoOwnedCompact, ToOwned)]
+#[ownable(owned_type = "InnerOwned")]
+struct Inner<'a>(Cow<'a, str>);
+
+#[derive(Debug, PartialEq, IntoOwned, ToOwned)]
+#[ownable(reference = "'r", owned_type = "TestEnumOwned")]
+enum TestEnum<'a, 'r> {
+    Named {
+        cow: Cow<'a, str>,
+        reference: &'r str,
+    },
+    Unnamed(Cow<'a, str>, #[ownable(clone)] String),
+    Unit,
+}
+
+// The companion types are defined by `Ownable`, even without `IntoOwned` and `ToBorrowed`.
+#[derive(Debug, PartialEq, Ownable)]
+#[ownable(into_owned = false, to_borrowed = false)]
+#[ownable(owned_type = "TestOwnableOwned", view_type = "TestOwnableRef")]
+struct TestOwnable<'a> {
+    cow: Cow<'a, str>,
+    list: Vec<Cow<'a, str>>,
+}
+
+// Also if all derives of `Ownable` are disabled.
+#[derive(Debug, PartialEq, Ownable)]
+#[ownable(into_owned = false, to_borrowed = false, to_owned = false)]
+#[ownable(owned_type = "TestCompanionsOwned", view_type = "TestCompanionsRef")]
+struct TestCompanions<'a> {
+    cow: Cow<'a, str>,
+}
+
+fn test_value(input: &str) -> Test<'_> {
+    Test {
+        cow: Cow::Borrowed(input),
+        bytes: Cow::Borrowed(input.as_bytes()),
+        nested: Some(Box::new(Test {
+            cow: Cow::Owned("owned".to_string()),
+            bytes: Cow::Owned(vec![1, 2, 3]),
+            nested: None,
+            inner: Inner(Cow::Borrowed(input)),
+            map: BTreeMap::new(),
+            cloned: "cloned".to_string(),
+            copy: 1,
+        })),
+        inner: Inner(Cow::Owned("inner".to_string())),
+        map: BTreeMap::from([(1, Cow::Borrowed(input))]),
+        cloned: "cloned".to_string(),
+        copy: 0,
+    }
+}
+
+#[test]
+fn test() {
+    let input = "input".to_string();
+    let v0 = test_value(&input);
+    let v1: TestOwned = v0.to_owned();
+    // the fields are plain owned values
+    let cow: &String = &v1.cow;
+    let bytes: &Vec<u8> = &v1.bytes;
+    let inner: &InnerOwned = &v1.inner;
+    assert_eq!(cow, "input");
+    assert_eq!(bytes, b"input");
+    assert_eq!(inner.0, "inner");
+    assert_eq!(v1.nested.as_ref().unwrap().inner.0, "input");
+    assert_eq!(v1.map[&1], "input");
+    assert_eq!(v1.as_borrowed(), v0);
+    assert!(matches!(v1.as_borrowed().cow, Cow::Borrowed(_)));
+    let mut string = String::with_capacity(100);
+    string.push_str("compact");
+    let compact: InnerOwned = Inner(Cow::Owned(string)).into_owned_compact();
+    assert_eq!(compact.0.capacity(), 7);
+    let v2: TestOwned = v0.into_owned();
+    assert_eq!(v2.as_borrowed(), test_value(&input));
+}
+
+#[test]
+fn test_ownable() {
+    let input = "input".to_string();
+    let v0 = TestOwnable {
+        cow: Cow::Borrowed(&input),
+        list: vec![Cow::Borrowed(&input)],
+    };
+    let v1: TestOwnableOwned = v0.to_owned();
+    let cow: &String = &v1.cow;
+    assert_eq!(cow, "input");
+    // only the `Cow` directly at a field is replaced
+    let list: &Vec<Cow<'static, str>> = &v1.list;
+    assert!(matches!(&list[..], [Cow::Owned(l)] if l == "input"));
+    assert_eq!(v1.as_borrowed(), v0);
+    let view: TestOwnableRef<'_> = v0.view();
+    assert_eq!(view.cow, "input");
+
+    let v2 = TestCompanionsOwned {
+        cow: "input".to_string(),
+    };
+    let v3: TestCompanions<'_> = v2.as_borrowed();
+    let view: TestCompanionsRef<'_> = v3.view();
+    assert_eq!(view.cow, "input");
+}
+
+#[test]
+fn test_enum() {
+    let input = "input".to_string();
+    let v0: TestEnum<'_, 'static> = TestEnum::Named {
+        cow: Cow::Borrowed(&input),
+        reference: "reference",
+    };
+    let v1: TestEnumOwned<'static> = v0.to_owned();
+    if let TestEnumOwned::Named { cow, reference } = &v1 {
+        let cow: &String = cow;
+        assert_eq!((cow.as_str(), *reference), ("input", "reference"));
+    } else {
+        panic!("wrong variant");
+    }
+    assert_eq!(v1.as_borrowed(), v0);
+    let v2 = TestEnum::Unnamed(Cow::Borrowed("unnamed"), "cloned".to_string());
+    assert_eq!(v2.to_owned().as_borrowed(), v2);
+    assert_eq!(
+        TestEnum::Unnamed(Cow::Borrowed("unnamed"), "cloned".to_string())
+            .into_owned()
+            .as_borrowed(),
+        v2
+    );
+    assert_eq!(TestEnum::Unit.into_owned().as_borrowed(), TestEnum::Unit);
+}
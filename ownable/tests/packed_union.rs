@@ -0,0 +1,78 @@
+use ownable::{ForEachBorrow, IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+// The fields of a packed struct are copied out before they are converted.
+#[derive(Debug, Clone, Copy, PartialEq, IntoOwned, ToBorrowed, ToOwned, ForEachBorrow)]
+#[repr(C, packed)]
+struct Header {
+    version: u8,
+    length: u32,
+    #[ownable(clone)]
+    checksum: u64,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned, ForEachBorrow)]
+struct Test<'a> {
+    header: Header,
+    payload: Cow<'a, [u8]>,
+}
+
+// The fields of a packed struct can be moved by the consuming functions.
+#[derive(IntoOwned)]
+#[repr(packed)]
+struct TestPacked<'a> {
+    flags: u16,
+    payload: Cow<'a, str>,
+}
+
+// A union is copied as a whole (its lifetimes are kept).
+#[derive(Clone, Copy, IntoOwned, ToBorrowed, ToOwned)]
+union TestUnion<'b> {
+    number: u64,
+    text: &'b str,
+}
+
+#[test]
+fn test() {
+    let payload = vec![1, 2, 3];
+    let v0 = Test {
+        header: Header {
+            version: 1,
+            length: 3,
+            checksum: 6,
+        },
+        payload: Cow::Borrowed(&payload),
+    };
+    let v1: Test<'_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let mut count = 0;
+    v1.for_each_borrow(&mut |_| count += 1);
+    assert_eq!(count, 1);
+    let v2: Test<'static> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: Test<'static> = v0.into_owned();
+    assert_eq!(v2, v3);
+
+    let packed = TestPacked {
+        flags: 2,
+        payload: Cow::Borrowed("payload"),
+    };
+    let packed: TestPacked<'static> = packed.into_owned();
+    let TestPacked { flags, payload } = packed;
+    assert_eq!(flags, 2);
+    assert!(matches!(payload, Cow::Owned(p) if p == "payload"));
+}
+
+#[test]
+fn test_union() {
+    let text = "text".to_string();
+    let v0 = TestUnion { text: &text };
+    let v1: TestUnion<'_> = v0.to_borrowed();
+    let v2: TestUnion<'_> = v0.to_owned();
+    let v3: TestUnion<'_> = v0.into_owned();
+    for v in [v1, v2, v3] {
+        assert_eq!(unsafe { v.text }, "text");
+    }
+    let v4 = TestUnion { number: 42 }.to_owned();
+    assert_eq!(unsafe { v4.number }, 42);
+}
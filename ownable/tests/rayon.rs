@@ -0,0 +1,53 @@
+#![cfg(feature = "rayon")]
+
+use ownable::traits::{ParIntoOwned, ParToOwned};
+use ownable::{IntoOwned, ToOwned};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, PartialEq, IntoOwned, ToOwned)]
+struct Test<'a> {
+    #[ownable(parallel)]
+    always: Vec<Cow<'a, str>>,
+    #[ownable(parallel = 2)]
+    above: HashMap<u32, Cow<'a, str>>,
+    #[ownable(parallel = 1000)]
+    below: BTreeMap<u32, Cow<'a, str>>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToOwned)]
+enum TestEnum<'a> {
+    Unnamed(#[ownable(parallel = 2)] Box<[Cow<'a, str>]>),
+}
+
+#[test]
+fn test() {
+    let value: String = "value".to_string();
+    let v0: Test<'_> = Test {
+        always: vec![Cow::Borrowed(&value); 10],
+        above: (0..10).map(|i| (i, Cow::Borrowed(&*value))).collect(),
+        below: (0..10).map(|i| (i, Cow::Borrowed(&*value))).collect(),
+    };
+    let owned: Test<'static> = v0.to_owned();
+    assert_eq!(v0, owned);
+    assert_eq!(v0.into_owned(), owned);
+}
+
+#[test]
+fn test_enum() {
+    let value: String = "value".to_string();
+    let v0: TestEnum<'_> = TestEnum::Unnamed(vec![Cow::Borrowed(&*value); 10].into());
+    let owned: TestEnum<'static> = v0.to_owned();
+    assert_eq!(v0, owned);
+    assert_eq!(v0.into_owned(), owned);
+}
+
+#[test]
+fn test_collections() {
+    let value: String = "value".to_string();
+    let v0: Vec<Cow<'_, str>> = vec![Cow::Borrowed(&value); 10];
+    let owned: Vec<Cow<'static, str>> = v0.par_to_owned();
+    assert_eq!(v0, owned);
+    let v0: Vec<Cow<'static, str>> = v0.par_into_owned();
+    assert!(v0.iter().all(|v| matches!(v, Cow::Owned(_))));
+}
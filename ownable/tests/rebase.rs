@@ -0,0 +1,123 @@
+use ownable::Rebase;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq, Rebase)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    bytes: Cow<'a, [u8]>,
+    nested: Option<Box<Test<'a>>>,
+    list: Vec<Cow<'a, str>>,
+}
+
+#[derive(Debug, PartialEq, Rebase)]
+#[ownable(reference = "'r")]
+enum TestEnum<'a, 'r> {
+    Named {
+        cow: Cow<'a, str>,
+        reference: &'r str,
+    },
+    Unnamed(Cow<'a, [u8]>, u8),
+}
+
+fn is_within<T: ?Sized>(buffer: &[u8], value: &T) -> bool {
+    let start = buffer.as_ptr() as usize;
+    let address = value as *const T as *const u8 as usize;
+    address >= start && address + std::mem::size_of_val(value) <= start + buffer.len()
+}
+
+#[test]
+fn test() {
+    let input: String = "some input".to_string();
+    let other: String = "other".to_string();
+    let test: Test<'_> = Test {
+        cow: Cow::Borrowed(&input[5..]),
+        bytes: Cow::Borrowed(&input.as_bytes()[..4]),
+        nested: Some(Box::new(Test {
+            cow: Cow::Borrowed(&other),
+            bytes: Cow::Owned(b"owned".to_vec()),
+            nested: None,
+            list: vec![],
+        })),
+        list: vec![Cow::Borrowed(&input)],
+    };
+    let buffer: Arc<[u8]> = Arc::from(input.as_bytes());
+    let rebased: Test<'_> = test.rebase(input.as_bytes(), &buffer);
+    assert_eq!(test, rebased);
+    assert!(matches!(&rebased.cow, Cow::Borrowed(v) if is_within(&buffer, *v)));
+    assert!(matches!(&rebased.bytes, Cow::Borrowed(v) if is_within(&buffer, *v)));
+    assert!(matches!(&rebased.list[0], Cow::Borrowed(v) if is_within(&buffer, *v)));
+    let nested = rebased.nested.as_ref().unwrap();
+    // not from the old buffer, thus cloned
+    assert!(matches!(nested.cow, Cow::Owned(_)));
+    assert!(matches!(nested.bytes, Cow::Owned(_)));
+}
+
+#[test]
+fn test_enum() {
+    let input: String = "some input".to_string();
+    let buffer: Vec<u8> = input.as_bytes().to_vec();
+    let v0: TestEnum<'_, '_> = TestEnum::Named {
+        cow: Cow::Borrowed(&input[5..]),
+        reference: "reference",
+    };
+    let v1: TestEnum<'_, '_> = v0.rebase(input.as_bytes(), &buffer);
+    assert_eq!(v0, v1);
+    assert!(matches!(v1, TestEnum::Named { cow: Cow::Borrowed(v), .. } if is_within(&buffer, v)));
+    let v2: TestEnum<'_, '_> = TestEnum::Unnamed(Cow::Borrowed(&input.as_bytes()[5..]), 1);
+    let v3: TestEnum<'_, '_> = v2.rebase(input.as_bytes(), &buffer);
+    assert_eq!(v2, v3);
+    assert!(matches!(v3, TestEnum::Unnamed(Cow::Borrowed(v), 1) if is_within(&buffer, v)));
+}
+
+#[derive(Debug, PartialEq, Rebase)]
+enum TestNames<'a> {
+    Named { old: Cow<'a, str>, new: u8 },
+}
+
+#[test]
+fn test_names() {
+    let input: String = "input".to_string();
+    let buffer: Vec<u8> = input.as_bytes().to_vec();
+    let v0: TestNames<'_> = TestNames::Named {
+        old: Cow::Borrowed(&input),
+        new: 1,
+    };
+    let v1: TestNames<'_> = v0.rebase(input.as_bytes(), &buffer);
+    assert_eq!(v0, v1);
+}
+
+#[derive(Debug, PartialEq, Rebase)]
+struct TestOther<'a> {
+    numbers: Cow<'a, [u32]>,
+    sized: Cow<'a, u32>,
+    #[cfg(feature = "std")]
+    c_str: Cow<'a, std::ffi::CStr>,
+    #[cfg(feature = "std")]
+    path: Cow<'a, std::path::Path>,
+}
+
+#[test]
+fn test_other() {
+    let input: Vec<u8> = b"input\0".to_vec();
+    let buffer: Vec<u8> = input.clone();
+    let numbers: [u32; 2] = [1, 2];
+    let v0: TestOther<'_> = TestOther {
+        numbers: Cow::Borrowed(&numbers),
+        sized: Cow::Borrowed(&numbers[0]),
+        #[cfg(feature = "std")]
+        c_str: Cow::Borrowed(std::ffi::CStr::from_bytes_with_nul(&input).unwrap()),
+        #[cfg(feature = "std")]
+        path: Cow::Borrowed(std::path::Path::new("path")),
+    };
+    let v1: TestOther<'_> = v0.rebase(&input, &buffer);
+    assert_eq!(v0, v1);
+    // other payloads are cloned
+    assert!(matches!(v1.numbers, Cow::Owned(_)));
+    assert!(matches!(v1.sized, Cow::Owned(1)));
+    #[cfg(feature = "std")]
+    {
+        assert!(matches!(&v1.c_str, Cow::Borrowed(v) if is_within(&buffer, *v)));
+        assert!(matches!(v1.path, Cow::Owned(_)));
+    }
+}
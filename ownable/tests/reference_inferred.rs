@@ -0,0 +1,58 @@
+use ownable::{IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+// The lifetime `'b` is inferred to be used solely for references.
+#[derive(Debug, PartialEq, ToBorrowed, ToOwned, IntoOwned)]
+struct Test<'a, 'b> {
+    cow: Cow<'a, str>,
+    referenced: &'b str,
+    nested: Option<Box<Test<'a, 'b>>>,
+}
+
+// The lifetime `'b` is also used by another type, thus it's not inferred and has to be set.
+#[derive(Debug, PartialEq, ToBorrowed, ToOwned, IntoOwned)]
+#[ownable(reference = "'b")]
+enum TestEnum<'a, 'b> {
+    Named {
+        test: Test<'a, 'b>,
+        referenced: Option<&'b str>,
+    },
+    Unnamed(Cow<'a, str>),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<'_, '_> = Test {
+        cow: Cow::Borrowed(&value),
+        referenced: "referenced",
+        nested: None,
+    };
+    let v1: Test<'_, '_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: Test<'static, '_> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: Test<'static, '_> = v0.into_owned();
+    assert_eq!(v2, v3);
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<'_, '_> = TestEnum::Named {
+        test: Test {
+            cow: Cow::Borrowed(&value),
+            referenced: &value,
+            nested: None,
+        },
+        referenced: Some(&value),
+    };
+    let v1: TestEnum<'_, '_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: TestEnum<'static, '_> = v0.to_owned();
+    assert_eq!(v0, v2);
+    let v3: TestEnum<'static, '_> = v0.into_owned();
+    assert_eq!(v2, v3);
+    let v4: TestEnum<'static, '_> = TestEnum::Unnamed(Cow::Borrowed(&value)).into_owned();
+    assert_eq!(v4, TestEnum::Unnamed(Cow::Borrowed("value")));
+}
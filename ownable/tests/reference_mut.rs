@@ -0,0 +1,75 @@
+use ownable::{ForEachBorrow, IntoLeaked, IntoOwned, Leaker, MapCows};
+use std::borrow::Cow;
+
+// The mutable references can only be moved, thus only the consuming functions are derived.
+#[derive(Debug, PartialEq, IntoOwned, IntoLeaked, ForEachBorrow, MapCows)]
+struct Test<'a, 'b> {
+    cow: Cow<'a, str>,
+    diagnostics: &'b mut Vec<String>,
+    nested: Option<&'b mut u32>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ForEachBorrow)]
+#[ownable(reference = "'b")]
+enum TestEnum<'a, 'b> {
+    Named {
+        cow: Cow<'a, str>,
+        diagnostics: &'b mut Vec<String>,
+    },
+    Unnamed(Test<'a, 'b>),
+}
+
+#[test]
+fn test() {
+    let mut diagnostics = Vec::new();
+    let mut count = 0;
+    let v0: Test<'static, '_> = {
+        let value = "value".to_string();
+        let v0 = Test {
+            cow: Cow::Borrowed(&value),
+            diagnostics: &mut diagnostics,
+            nested: Some(&mut count),
+        };
+        let mut ranges = 0;
+        v0.for_each_borrow(&mut |_| ranges += 1);
+        assert_eq!(ranges, 1);
+        v0.into_owned()
+    };
+    let v1 = v0.into_leaked(&mut Leaker::new());
+    let v2 = v1.map_cows(&mut ownable::map::str_mapper(|s| s.to_uppercase().into()));
+    assert_eq!(v2.cow, "VALUE");
+    v2.diagnostics.push(v2.cow.to_string());
+    *v2.nested.unwrap() += 1;
+    assert_eq!(diagnostics, vec!["VALUE".to_string()]);
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_enum() {
+    let mut diagnostics = Vec::new();
+    let v0: TestEnum<'static, '_> = {
+        let value = "value".to_string();
+        TestEnum::Named {
+            cow: Cow::Borrowed(&value),
+            diagnostics: &mut diagnostics,
+        }
+        .into_owned()
+    };
+    if let TestEnum::Named { cow, diagnostics } = v0 {
+        diagnostics.push(cow.into_owned());
+    }
+    let mut count = 0;
+    let v1: TestEnum<'static, '_> = TestEnum::Unnamed(Test {
+        cow: Cow::Borrowed("unnamed"),
+        diagnostics: &mut diagnostics,
+        nested: Some(&mut count),
+    })
+    .into_owned();
+    if let TestEnum::Unnamed(test) = v1 {
+        test.diagnostics.push(test.cow.into_owned());
+    }
+    assert_eq!(
+        diagnostics,
+        vec!["value".to_string(), "unnamed".to_string()]
+    );
+}
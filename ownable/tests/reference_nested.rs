@@ -0,0 +1,70 @@
+use ownable::{ForEachBorrow, IntoLeaked, IntoOwned, Leaker, MapCows, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq)]
+struct Node {
+    id: u32,
+}
+
+// The references are nested within containers and tuples.
+#[derive(Debug, PartialEq, ToBorrowed, ToOwned, IntoOwned, IntoLeaked, ForEachBorrow, MapCows)]
+struct Test<'a, 'b> {
+    cow: Cow<'a, str>,
+    option: Option<&'b str>,
+    nodes: Vec<&'b Node>,
+    map: BTreeMap<u32, &'b Node>,
+    tuple: (&'b str, Cow<'a, str>),
+}
+
+#[derive(Debug, PartialEq, ToBorrowed, ToOwned, IntoOwned)]
+#[ownable(reference = "'b")]
+enum TestEnum<'a, 'b> {
+    Named { nodes: Box<[&'b Node]> },
+    Unnamed(Option<(&'b str, Cow<'a, str>)>),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let node = Node { id: 1 };
+    let v0: Test<'_, '_> = Test {
+        cow: Cow::Borrowed(&value),
+        option: Some(&value),
+        nodes: vec![&node],
+        map: BTreeMap::from([(1, &node)]),
+        tuple: (&value, Cow::Borrowed(&value)),
+    };
+    let v1: Test<'_, '_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let mut count = 0;
+    v1.for_each_borrow(&mut |_| count += 1);
+    assert_eq!(count, 2);
+    let v2: Test<'static, '_> = v0.to_owned();
+    assert_eq!(v0, v2);
+    assert!(std::ptr::eq(v2.nodes[0], &node));
+    let v3: Test<'static, '_> = v1.into_owned();
+    assert_eq!(v2, v3);
+    let v4: Test<'static, '_> = v3.into_leaked(&mut Leaker::new());
+    assert_eq!(v2, v4);
+    let v5: Test<'static, '_> =
+        v4.map_cows(&mut ownable::map::str_mapper(|s| s.to_uppercase().into()));
+    assert_eq!(v5.cow, "VALUE");
+    assert_eq!(v5.option, Some("value"));
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let node = Node { id: 2 };
+    let v0: TestEnum<'_, '_> = TestEnum::Named {
+        nodes: vec![&node].into_boxed_slice(),
+    };
+    let v1: TestEnum<'static, '_> = v0.into_owned();
+    assert!(matches!(&v1, TestEnum::Named { nodes } if nodes[0].id == 2));
+    let v2: TestEnum<'_, '_> = TestEnum::Unnamed(Some((&value, Cow::Borrowed(&value))));
+    let v3: TestEnum<'_, '_> = v2.to_borrowed();
+    assert_eq!(v2, v3);
+    let v4: TestEnum<'static, '_> = v2.to_owned();
+    assert_eq!(v2, v4);
+}
@@ -0,0 +1,78 @@
+use ownable::{ForEachBorrow, IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::ops::Range;
+
+// A type which doesn't implement the traits.
+#[derive(Debug, Default, PartialEq)]
+struct Cache(RefCell<Vec<u32>>);
+
+#[derive(Debug, PartialEq, ForEachBorrow, IntoOwned, ToBorrowed, ToOwned)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    #[ownable(skip)]
+    cache: Cache,
+    #[ownable(default)]
+    source: Option<&'a str>,
+    #[ownable(default = "0..0")]
+    span: Range<usize>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+enum TestEnum<'a> {
+    Named {
+        cow: Cow<'a, str>,
+        #[ownable(skip)]
+        cache: Cache,
+    },
+    Unnamed(Cow<'a, str>, #[ownable(default = "usize::MAX")] usize),
+}
+
+#[test]
+fn test() {
+    let input = "some input".to_string();
+    let v0 = Test {
+        cow: Cow::Borrowed(&input),
+        cache: Cache(RefCell::new(vec![1])),
+        source: Some(&input),
+        span: 5..10,
+    };
+    let v1 = v0.to_borrowed();
+    assert_eq!(v1.cache, Cache::default());
+    assert_eq!((v1.source, v1.span), (Some(input.as_str()), 5..10));
+    let v2 = v0.to_owned();
+    assert_eq!(
+        v2,
+        Test {
+            cow: Cow::Borrowed("some input"),
+            cache: Cache::default(),
+            source: None,
+            span: 0..0,
+        }
+    );
+    let mut count = 0;
+    v0.for_each_borrow(&mut |_| count += 1);
+    assert_eq!(count, 1);
+    assert_eq!(v0.into_owned(), v2);
+}
+
+#[test]
+fn test_enum() {
+    let v0 = TestEnum::Named {
+        cow: Cow::Borrowed("input"),
+        cache: Cache(RefCell::new(vec![1])),
+    };
+    assert_eq!(
+        v0.into_owned(),
+        TestEnum::Named {
+            cow: Cow::Borrowed("input"),
+            cache: Cache::default(),
+        }
+    );
+    let v1 = TestEnum::Unnamed(Cow::Borrowed("input"), 1);
+    assert_eq!(v1.to_borrowed(), v1);
+    assert_eq!(
+        v1.to_owned(),
+        TestEnum::Unnamed(Cow::Borrowed("input"), usize::MAX)
+    );
+}
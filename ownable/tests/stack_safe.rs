@@ -0,0 +1,145 @@
+#![cfg(feature = "stack_safe")]
+
+use ownable::stack::{guard, with_max_depth, DepthExceeded};
+use ownable::{IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+const DEPTH: usize = 100_000;
+
+#[derive(Debug, PartialEq, IntoOwned, ToOwned, ToBorrowed)]
+#[ownable(stack_safe)]
+struct List<'a> {
+    value: Cow<'a, str>,
+    next: Option<Box<List<'a>>>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToOwned, ToBorrowed)]
+#[ownable(stack_safe)]
+enum Json<'a> {
+    String(Cow<'a, str>),
+    Array(Vec<Json<'a>>),
+}
+
+// The limit also applies to the parallel conversions.
+#[cfg(feature = "rayon")]
+#[derive(IntoOwned, ToOwned)]
+#[ownable(stack_safe)]
+struct Tree<'a> {
+    value: Cow<'a, str>,
+    #[ownable(parallel)]
+    children: Vec<Tree<'a>>,
+}
+
+fn list(value: &str) -> List<'_> {
+    let mut list = List {
+        value: Cow::Borrowed(value),
+        next: None,
+    };
+    for _ in 1..DEPTH {
+        list = List {
+            value: Cow::Borrowed(value),
+            next: Some(Box::new(list)),
+        };
+    }
+    list
+}
+
+fn json(value: &str) -> Json<'_> {
+    let mut json = Json::String(Cow::Borrowed(value));
+    for _ in 1..DEPTH {
+        json = Json::Array(vec![json]);
+    }
+    json
+}
+
+// the default drop is not stack safe
+fn drop_list(mut list: List<'_>) {
+    while let Some(next) = list.next.take() {
+        list = *next;
+    }
+}
+
+fn drop_json(mut json: Json<'_>) {
+    while let Json::Array(mut array) = json {
+        json = array.pop().unwrap();
+    }
+}
+
+#[test]
+fn test_list() {
+    let value: String = "value".to_string();
+    let list: List<'_> = list(&value);
+    drop_list(list.to_borrowed());
+    drop_list(list.to_owned());
+    drop_list(list.into_owned());
+}
+
+#[test]
+fn test_json() {
+    let value: String = "value".to_string();
+    let json: Json<'_> = json(&value);
+    drop_json(json.to_borrowed());
+    drop_json(json.to_owned());
+    drop_json(json.into_owned());
+}
+
+#[test]
+fn test_max_depth() {
+    let value: String = "value".to_string();
+    let v0: List<'_> = list(&value);
+    assert_eq!(
+        with_max_depth(1000, || v0.to_owned()).err(),
+        Some(DepthExceeded { max_depth: 1000 })
+    );
+    let v1 = with_max_depth(DEPTH, || v0.to_owned()).unwrap();
+    drop_list(v0);
+    drop_list(v1);
+}
+
+#[test]
+fn test_max_depth_nested() {
+    let value: String = "value".to_string();
+    let v0: List<'_> = List {
+        value: Cow::Borrowed(&value),
+        next: Some(Box::new(List {
+            value: Cow::Borrowed(&value),
+            next: None,
+        })),
+    };
+    // the levels of the enclosing conversion aren't counted
+    let v1 = guard(|| guard(|| with_max_depth(2, || v0.to_owned()))).unwrap();
+    assert_eq!(v0, v1);
+    assert_eq!(
+        guard(|| with_max_depth(1, || v0.to_owned())).err(),
+        Some(DepthExceeded { max_depth: 1 })
+    );
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_max_depth_parallel() {
+    let value: String = "value".to_string();
+    let mut v0 = Tree {
+        value: Cow::Borrowed(&value),
+        children: Vec::new(),
+    };
+    // the children are converted by the worker threads
+    for _ in 1..6 {
+        v0 = Tree {
+            value: Cow::Borrowed(&value),
+            children: vec![
+                v0,
+                Tree {
+                    value: Cow::Borrowed(&value),
+                    children: Vec::new(),
+                },
+            ],
+        };
+    }
+    assert_eq!(
+        with_max_depth(5, || v0.to_owned()).err(),
+        Some(DepthExceeded { max_depth: 5 })
+    );
+    let v1 = with_max_depth(6, || v0.into_owned()).unwrap();
+    assert_eq!(v1.value, "value");
+}
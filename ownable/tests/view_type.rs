@@ -0,0 +1,106 @@
+use ownable::traits::AsBorrowed;
+use ownable::ToBorrowed;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, ToBorrowed)]
+#[ownable(view_type = "TestRef")]
+pub struct Test<'a> {
+    pub cow: Cow<'a, str>,
+    #[ownable(clone)]
+    string: String,
+    #[ownable(clone)]
+    vec: Vec<u32>,
+    nested: Option<Box<Test<'a>>>,
+    #[ownable(clone)]
+    boxed: Box<u64>,
+    map: BTreeMap<u32, Cow<'a, str>>,
+    copy: usize,
+    // only the types of std are viewed as their contents, also with the full path
+    full: std::vec::Vec<u32>,
+    #[ownable(clone)]
+    other: other::Vec,
+}
+
+mod other {
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Vec(pub u32);
+}
+
+// The function `view` is renamed, thus it doesn't collide with the own one.
+#[derive(Debug, PartialEq, ToBorrowed)]
+#[ownable(
+    reference = "'r",
+    view_type = "TestEnumRef",
+    function(view = "view_all")
+)]
+enum TestEnum<'a, 'r> {
+    Named {
+        cow: Cow<'a, [u8]>,
+        reference: &'r str,
+    },
+    Unnamed(Cow<'a, str>, #[ownable(clone)] String),
+    Unit,
+}
+
+impl TestEnum<'_, '_> {
+    fn view(&self) -> bool {
+        matches!(self, TestEnum::Unit)
+    }
+}
+
+#[test]
+fn test() {
+    let input = "input".to_string();
+    let v0 = Test {
+        cow: Cow::Owned(input.clone()),
+        string: "string".to_string(),
+        vec: vec![1, 2],
+        nested: None,
+        boxed: Box::new(3),
+        map: BTreeMap::from([(1, Cow::Borrowed(input.as_str()))]),
+        copy: 4,
+        full: vec![5],
+        other: other::Vec(6),
+    };
+    let view: TestRef<'_> = v0.view();
+    let cow: &str = view.cow;
+    let string: &str = view.string;
+    let vec: &[u32] = view.vec;
+    let boxed: &u64 = view.boxed;
+    let copy: &usize = view.copy;
+    assert_eq!(
+        (cow, string, vec, boxed, copy),
+        ("input", "string", &[1, 2][..], &3, &4)
+    );
+    // the view references the values
+    assert_eq!(cow.as_ptr(), v0.cow.as_ptr());
+    assert_eq!(string.as_ptr(), v0.string.as_ptr());
+    assert_eq!(view.nested, &None);
+    assert_eq!(view.map[&1], "input");
+    assert_eq!(v0.as_borrowed().copy, &4);
+    let full: &[u32] = view.full;
+    let other: &other::Vec = view.other;
+    assert_eq!((full, other), (&[5][..], &other::Vec(6)));
+}
+
+#[test]
+fn test_enum() {
+    let v0: TestEnum<'_, 'static> = TestEnum::Named {
+        cow: Cow::Borrowed(b"input"),
+        reference: "reference",
+    };
+    match v0.view_all() {
+        TestEnumRef::Named { cow, reference } => {
+            assert_eq!((cow, reference), (&b"input"[..], "reference"));
+        }
+        _ => panic!("wrong variant"),
+    }
+    let v1: TestEnum<'_, 'static> = TestEnum::Unnamed(Cow::Borrowed("cow"), "string".to_string());
+    match v1.view_all() {
+        TestEnumRef::Unnamed(cow, string) => assert_eq!((cow, string), ("cow", "string")),
+        _ => panic!("wrong variant"),
+    }
+    assert!(matches!(TestEnum::Unit.view_all(), TestEnumRef::Unit));
+    assert!(TestEnum::Unit.view());
+}
@@ -0,0 +1,110 @@
+use ownable::visit::{FieldPath, Visitor};
+use ownable::OwnableVisit;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+#[derive(Debug, PartialEq, OwnableVisit)]
+struct Test<'a> {
+    cow: Cow<'a, str>,
+    nested: Option<Box<Test<'a>>>,
+    map: BTreeMap<u32, Cow<'a, [u8]>>,
+}
+
+#[derive(Debug, PartialEq, OwnableVisit)]
+#[ownable(reference = "'r")]
+enum TestEnum<'a, 'r> {
+    Named {
+        cow: Cow<'a, str>,
+        reference: &'r str,
+    },
+    Unnamed(Cow<'a, str>, #[ownable(clone)] String, (u8, Cow<'a, str>)),
+    Unit,
+}
+
+#[derive(Debug, PartialEq, OwnableVisit)]
+struct TestUnit;
+
+#[derive(Default)]
+struct Record(Vec<(String, bool, usize)>);
+
+impl Visitor for Record {
+    fn visit_borrowed<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, value: &T) {
+        self.0
+            .push((path.to_string(), true, std::mem::size_of_val(value)));
+    }
+
+    fn visit_owned<T: ToOwned + ?Sized>(&mut self, path: &FieldPath<'_>, value: &T::Owned) {
+        use std::borrow::Borrow;
+        let value: &T = value.borrow();
+        self.0
+            .push((path.to_string(), false, std::mem::size_of_val(value)));
+    }
+}
+
+#[test]
+fn test() {
+    let input: String = "some input".to_string();
+    let v0: Test<'_> = Test {
+        cow: Cow::Borrowed(&input[5..]),
+        nested: Some(Box::new(Test {
+            cow: Cow::Owned("owned".to_string()),
+            nested: None,
+            map: BTreeMap::from([(1, Cow::Borrowed(input.as_bytes()))]),
+        })),
+        map: BTreeMap::new(),
+    };
+    let mut record = Record::default();
+    v0.visit(&FieldPath::root(), &mut record);
+    assert_eq!(
+        record.0,
+        [
+            ("cow".to_string(), true, 5),
+            ("nested.cow".to_string(), false, 5),
+            ("nested.map[0].value".to_string(), true, 10),
+        ]
+    );
+}
+
+#[test]
+fn test_enum() {
+    let input: String = "some input".to_string();
+    let v0: TestEnum<'_, '_> = TestEnum::Named {
+        cow: Cow::Borrowed(&input),
+        reference: "reference",
+    };
+    let mut record = Record::default();
+    v0.visit(&FieldPath::root(), &mut record);
+    assert_eq!(record.0, [("Named.cow".to_string(), true, 10)]);
+
+    let v1: TestEnum<'_, '_> = TestEnum::Unnamed(
+        Cow::Owned("owned".to_string()),
+        String::new(),
+        (0, Cow::Borrowed("x")),
+    );
+    let mut record = Record::default();
+    v1.visit(&FieldPath::root(), &mut record);
+    assert_eq!(
+        record.0,
+        [
+            ("Unnamed.0".to_string(), false, 5),
+            ("Unnamed.2.1".to_string(), true, 1),
+        ]
+    );
+
+    let mut record = Record::default();
+    TestEnum::Unit.visit(&FieldPath::root(), &mut record);
+    TestUnit.visit(&FieldPath::root(), &mut record);
+    assert!(record.0.is_empty());
+}
+
+#[test]
+fn test_path() {
+    let root = FieldPath::root();
+    let field = root.join(ownable::visit::PathSegment::Field("field"));
+    let index = field.join(ownable::visit::PathSegment::Index(3));
+    let key = index.join(ownable::visit::PathSegment::Key(1));
+    assert_eq!(root.to_string(), "");
+    assert_eq!(index.to_string(), "field[3]");
+    assert_eq!(key.to_string(), "field[3][1].key");
+    assert_eq!(key.parent().unwrap().segment(), index.segment());
+}
@@ -0,0 +1,72 @@
+use ownable::{IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+fn len(value: &str) -> usize {
+    value.len()
+}
+
+trait Item<'a> {
+    type Assoc;
+}
+
+impl<'a> Item<'a> for u8 {
+    type Assoc = Cow<'a, str>;
+}
+
+// A higher-ranked bound, an outlives relation to the reference lifetime `'b` and defaults.
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+struct Test<'a: 'b, 'b, F = fn(&str) -> usize, const N: usize = 2>
+where
+    F: Copy,
+    for<'x> F: Fn(&'x str) -> usize,
+{
+    cow: Cow<'a, str>,
+    referenced: &'b str,
+    #[ownable(clone)]
+    function: F,
+    array: [u8; N],
+}
+
+// The associated type of a projection is converted (for all lifetimes with a higher-ranked bound).
+#[derive(IntoOwned, ToBorrowed, ToOwned)]
+enum TestEnum<'a, T>
+where
+    for<'x> T: Item<'x>,
+{
+    Projection(<T as Item<'a>>::Assoc),
+    Cow(Cow<'a, str>),
+}
+
+#[test]
+fn test() {
+    let value = "value".to_string();
+    let v0: Test<'_, '_> = Test {
+        cow: Cow::Borrowed(&value),
+        referenced: &value,
+        function: len,
+        array: [1, 2],
+    };
+    let v1: Test<'_, '_> = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2: Test<'static, '_> = v0.to_owned();
+    assert_eq!(v0, v2);
+    assert_eq!((v2.function)(&v2.cow), 5);
+    let v3: Test<'static, '_> = v0.into_owned();
+    assert_eq!(v2, v3);
+}
+
+#[test]
+fn test_enum() {
+    let value = "value".to_string();
+    let v0: TestEnum<'_, u8> = TestEnum::Projection(Cow::Borrowed(value.as_str()));
+    let v1: TestEnum<'_, u8> = v0.to_borrowed();
+    assert!(matches!(&v1, TestEnum::Projection(Cow::Borrowed(v)) if v == &value));
+    let v2: TestEnum<'static, u8> = v0.to_owned();
+    assert!(matches!(&v2, TestEnum::Projection(Cow::Owned(v)) if v == &value));
+    // the lifetime is invariant, thus `v0` stays borrowed by `v1`
+    let v3: TestEnum<'static, u8> =
+        TestEnum::Projection(Cow::Borrowed(value.as_str())).into_owned();
+    assert!(matches!(&v3, TestEnum::Projection(Cow::Owned(v)) if v == &value));
+    let v4: TestEnum<'static, u8> = TestEnum::Cow(Cow::Borrowed(&value)).into_owned();
+    assert!(matches!(&v4, TestEnum::Cow(Cow::Owned(v)) if v == &value));
+}
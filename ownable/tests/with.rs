@@ -0,0 +1,83 @@
+use ownable::{IntoOwned, ToBorrowed, ToOwned};
+use std::borrow::Cow;
+
+// A third party type, which doesn't implement the traits.
+#[derive(Debug, PartialEq, Clone)]
+pub struct External<'a>(Cow<'a, str>);
+
+impl External<'_> {
+    fn into_owned(self) -> External<'static> {
+        External(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+mod external {
+    use super::External;
+    use std::borrow::Cow;
+
+    pub fn to_borrowed<'a>(value: &'a External<'_>) -> External<'a> {
+        External(Cow::Borrowed(&value.0))
+    }
+
+    pub fn to_owned(value: &External<'_>) -> External<'static> {
+        value.clone().into_owned()
+    }
+
+    pub fn into_owned(value: External<'_>) -> External<'static> {
+        value.into_owned()
+    }
+}
+
+fn into_owned_upper(value: External<'_>) -> External<'static> {
+    External(Cow::Owned(value.0.to_uppercase()))
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+struct Test<'a> {
+    #[ownable(with = "external")]
+    external: External<'a>,
+    #[ownable(with = "external", into_owned_with = "into_owned_upper")]
+    upper: External<'a>,
+    cow: Cow<'a, str>,
+}
+
+#[derive(Debug, PartialEq, IntoOwned, ToBorrowed, ToOwned)]
+enum TestEnum<'a> {
+    Named {
+        #[ownable(with = "external")]
+        external: External<'a>,
+    },
+    Unnamed(#[ownable(with = "external")] External<'a>),
+}
+
+#[test]
+fn test() {
+    let input = "input".to_string();
+    let v0 = Test {
+        external: External(Cow::Borrowed(&input)),
+        upper: External(Cow::Borrowed(&input)),
+        cow: Cow::Borrowed(&input),
+    };
+    let v1 = v0.to_borrowed();
+    assert_eq!(v0, v1);
+    let v2 = v0.to_owned();
+    assert_eq!(v0, v2);
+    assert!(matches!(v2.external.0, Cow::Owned(_)));
+    let v3 = v1.into_owned();
+    assert_eq!(v3.external.0, "input");
+    assert_eq!(v3.upper.0, "INPUT");
+    assert!(matches!(v3.cow, Cow::Owned(_)));
+}
+
+#[test]
+fn test_enum() {
+    let input = "input".to_string();
+    let v0 = TestEnum::Named {
+        external: External(Cow::Borrowed(&input)),
+    };
+    assert_eq!(v0.to_borrowed(), v0);
+    assert_eq!(v0.to_owned(), v0);
+    let v1 = TestEnum::Unnamed(External(Cow::Borrowed(&input)));
+    let v2: TestEnum<'static> = v1.into_owned();
+    assert_eq!(v2, TestEnum::Unnamed(External(Cow::Borrowed("input"))));
+}